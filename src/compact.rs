@@ -0,0 +1,111 @@
+use crate::{FrameCount, Framerate, Frames, Timecode, ToFrames};
+
+///A timecode stored as only its frame count and framerate. This is smaller than [`Timecode`],
+///but the hours, minutes, seconds, and frames have to be worked out every time it is displayed.
+///
+///```
+///# use timecode::{framerates::*, Timecode, Frames, compact::CompactTimecode};
+///let tc: Timecode<DF<30>> = "00:00:59;29".parse().unwrap();
+///let compact = CompactTimecode::from(tc) + Frames(1);
+///
+///assert_eq!(compact.to_string(), "00:01:00;02");
+///assert_eq!(Timecode::from(compact), tc + Frames(1));
+///```
+#[derive(Copy, Debug, Eq, PartialEq, Hash, Clone)]
+pub struct CompactTimecode<FR>(FrameCount, FR);
+
+impl<FR: Framerate> CompactTimecode<FR> {
+    pub fn framerate(&self) -> &FR {
+        &self.1
+    }
+
+    pub fn to_timecode(&self) -> Timecode<FR> {
+        Timecode::from_frames(&Frames(self.0), &self.1)
+    }
+}
+
+impl<FR: Framerate> ToFrames<FR> for CompactTimecode<FR> {
+    fn to_frame_count(&self) -> FrameCount {
+        self.0
+    }
+
+    fn from_frames(f: &Frames, fr: &FR) -> Self {
+        Self(f.0, *fr)
+    }
+}
+
+impl<FR: Framerate> From<Timecode<FR>> for CompactTimecode<FR> {
+    fn from(tc: Timecode<FR>) -> Self {
+        Self(tc.to_frame_count(), *tc.framerate())
+    }
+}
+
+impl<FR: Framerate> From<CompactTimecode<FR>> for Timecode<FR> {
+    fn from(tc: CompactTimecode<FR>) -> Self {
+        tc.to_timecode()
+    }
+}
+
+impl<FR: Framerate> std::ops::Add<Frames> for CompactTimecode<FR> {
+    type Output = Self;
+
+    fn add(self, rhs: Frames) -> Self::Output {
+        Self((Frames(self.0) + rhs).0, self.1)
+    }
+}
+
+impl<FR: Framerate> std::ops::Sub<Frames> for CompactTimecode<FR> {
+    type Output = Self;
+
+    ///PANIC: if rhs > lhs
+    fn sub(self, rhs: Frames) -> Self::Output {
+        Self((Frames(self.0) - rhs).0, self.1)
+    }
+}
+
+impl<FR: Framerate> std::fmt::Display for CompactTimecode<FR> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_timecode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framerates::*;
+
+    #[test]
+    fn agrees_with_timecode() {
+        for s in ["00:00:00;00", "00:00:59;29", "00:09:59;29", "23:59:59;29"] {
+            let tc: Timecode<DF<30>> = s.parse().unwrap();
+            let compact = CompactTimecode::from(tc);
+
+            assert_eq!(compact.to_string(), tc.to_string());
+            assert_eq!(compact.to_frame_count(), tc.to_frame_count());
+            assert_eq!(
+                (compact + Frames(3)).to_string(),
+                (tc + Frames(3)).to_string()
+            );
+            assert_eq!(Timecode::from(compact), tc);
+        }
+
+        let tc: Timecode<NDF<25>> = "01:00:00:00".parse().unwrap();
+        let compact = CompactTimecode::from(tc);
+        assert_eq!(
+            (compact - Frames(1)).to_string(),
+            (tc - Frames(1)).to_string()
+        );
+        assert_eq!(
+            CompactTimecode::from_frames(&Frames(90_000), &NDF::<25>),
+            compact
+        );
+    }
+
+    #[test]
+    fn smaller() {
+        assert!(
+            std::mem::size_of::<CompactTimecode<NDF<30>>>()
+                < std::mem::size_of::<Timecode<NDF<30>>>()
+        );
+    }
+}
@@ -0,0 +1,146 @@
+//!Film footage counts, where a position is written as feet and frames like `120+08`.
+
+use crate::{FrameCount, Frames, ToFrames};
+
+///Frames per foot of 35mm 4-perf film
+pub const FRAMES_PER_FOOT_35MM: FrameCount = 16;
+///Frames per foot of 16mm film
+pub const FRAMES_PER_FOOT_16MM: FrameCount = 40;
+
+///A film position in feet and frames. Displayed as `feet+frames`, such as `120+08`.
+///
+///```
+///# use timecode::{Frames, film::{FeetFrames, FRAMES_PER_FOOT_35MM}};
+///let ff = FeetFrames::parse("120+08", FRAMES_PER_FOOT_35MM).unwrap();
+///assert_eq!(ff.to_frames(), Frames(120 * 16 + 8));
+///assert_eq!(Frames(1928).to_feet_frames(FRAMES_PER_FOOT_35MM).to_string(), "120+08");
+///```
+#[derive(Copy, Debug, Eq, PartialEq, Hash, Clone)]
+pub struct FeetFrames {
+    feet: FrameCount,
+    frames: FrameCount,
+    frames_per_foot: FrameCount,
+}
+
+impl FeetFrames {
+    ///PANIC: if `frames_per_foot` is zero, or `frames` is not less than `frames_per_foot`
+    pub fn new(feet: FrameCount, frames: FrameCount, frames_per_foot: FrameCount) -> Self {
+        assert!(frames_per_foot > 0, "frames per foot must be at least 1");
+        assert!(frames < frames_per_foot, "frames must be less than a foot");
+
+        Self {
+            feet,
+            frames,
+            frames_per_foot,
+        }
+    }
+
+    ///Parse `feet+frames`. Returns `None` if `frames` is a foot or more.
+    pub fn parse(s: &str, frames_per_foot: FrameCount) -> Option<Self> {
+        let (feet, frames) = s.split_once('+')?;
+        let (feet, frames) = (feet.parse().ok()?, frames.parse().ok()?);
+
+        if frames_per_foot == 0 || frames >= frames_per_foot {
+            return None;
+        }
+
+        Some(Self::new(feet, frames, frames_per_foot))
+    }
+
+    pub fn feet(&self) -> FrameCount {
+        self.feet
+    }
+
+    pub fn frames(&self) -> FrameCount {
+        self.frames
+    }
+
+    pub fn frames_per_foot(&self) -> FrameCount {
+        self.frames_per_foot
+    }
+
+    ///Total number of frames from the start of the reel
+    ///
+    ///PANIC: if the total does not fit in a [`FrameCount`]
+    pub fn to_frames(&self) -> Frames {
+        let total = self
+            .feet
+            .checked_mul(self.frames_per_foot)
+            .and_then(|f| f.checked_add(self.frames))
+            .expect("Too large");
+        Frames(total)
+    }
+}
+
+impl Frames {
+    ///Split this frame count into feet and frames. See [`FeetFrames`].
+    ///
+    ///PANIC: if `frames_per_foot` is zero
+    pub fn to_feet_frames(&self, frames_per_foot: FrameCount) -> FeetFrames {
+        assert!(frames_per_foot > 0, "frames per foot must be at least 1");
+        FeetFrames::new(
+            self.0 / frames_per_foot,
+            self.0 % frames_per_foot,
+            frames_per_foot,
+        )
+    }
+}
+
+///The "framerate" here is the number of frames per foot
+impl ToFrames<FrameCount> for FeetFrames {
+    fn to_frame_count(&self) -> FrameCount {
+        self.to_frames().0
+    }
+
+    fn from_frames(f: &Frames, frames_per_foot: &FrameCount) -> Self {
+        f.to_feet_frames(*frames_per_foot)
+    }
+}
+
+impl std::fmt::Display for FeetFrames {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}+{:02}", self.feet, self.frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_35mm() {
+        for count in [0, 1, 15, 16, 17, 1928, 86400] {
+            let ff = Frames(count).to_feet_frames(FRAMES_PER_FOOT_35MM);
+            assert_eq!(ff.to_frames(), Frames(count));
+            assert_eq!(
+                FeetFrames::parse(&ff.to_string(), FRAMES_PER_FOOT_35MM),
+                Some(ff)
+            );
+        }
+
+        let ff = Frames(86400).to_feet_frames(FRAMES_PER_FOOT_35MM);
+        assert_eq!(ff.to_string(), "5400+00");
+    }
+
+    #[test]
+    fn sixteen_mm() {
+        let ff = FeetFrames::parse("10+39", FRAMES_PER_FOOT_16MM).unwrap();
+        assert_eq!(ff.to_frames(), Frames(439));
+        assert_eq!(ff.feet(), 10);
+        assert_eq!(ff.frames(), 39);
+        assert_eq!(
+            FeetFrames::from_frames(&Frames(439), &FRAMES_PER_FOOT_16MM),
+            ff
+        );
+
+        assert_eq!(FeetFrames::parse("10+39", FRAMES_PER_FOOT_35MM), None);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(FeetFrames::parse("120", FRAMES_PER_FOOT_35MM), None);
+        assert_eq!(FeetFrames::parse("120+", FRAMES_PER_FOOT_35MM), None);
+        assert_eq!(FeetFrames::parse("a+01", FRAMES_PER_FOOT_35MM), None);
+        assert_eq!(FeetFrames::parse("1+01", 0), None);
+    }
+}
@@ -5,12 +5,69 @@ pub trait Framerate: PartialEq + Copy {
     fn to_sep(&self) -> char;
     fn max_frame(&self) -> FrameCount;
     fn drop_frames(&self) -> Option<FrameCount>;
-    fn fr_ratio(&self) -> f32;
+    fn fr_ratio(&self) -> f64;
     fn fr_num(&self) -> u64;
     fn fr_denom(&self) -> u64;
     fn is_dropframe(&self) -> bool {
         self.drop_frames().is_some()
     }
+
+    ///Separators that a timecode at this framerate may be written with, for validating input as
+    ///it is typed. This is [`Framerate::to_sep`], plus `.` for non-dropframe timecodes.
+    ///
+    ///```
+    ///# use timecode::framerates::*;
+    ///assert_eq!(DF::<30>.legal_separators(), &[';']);
+    ///assert_eq!(NDF::<30>.legal_separators(), &[':', '.']);
+    ///```
+    fn legal_separators(&self) -> &'static [char] {
+        if self.is_dropframe() {
+            &[';']
+        } else {
+            &[':', '.']
+        }
+    }
+
+    ///Number of frames since `00:00:00:00` for a timecode with these fields at this framerate.
+    ///This is the same as [`ToFrames::to_frame_count`](crate::ToFrames::to_frame_count), without
+    ///needing to build a [`Timecode`](crate::Timecode) first.
+    ///
+    ///```
+    ///# use timecode::framerates::*;
+    ///assert_eq!(DF::<30>.frames_for(0, 1, 0, 2), 1800);
+    ///assert_eq!(NDF::<30>.frames_for(0, 1, 0, 2), 1802);
+    ///```
+    ///
+    ///Fields that were never validated (see
+    ///[`UnvalidatedTC::validate_unchecked`](crate::parser::UnvalidatedTC::validate_unchecked))
+    ///do not panic: dropped addresses like `00:01:00;00` count as the frame before them, and
+    ///counts too large for a [`FrameCount`] saturate at `FrameCount::MAX`.
+    fn frames_for(&self, h: u8, m: u8, s: u8, f: FrameCount) -> FrameCount {
+        //u64 fits any combination of fields, even out of range ones
+        let max_frame = self.max_frame() as u64;
+        let mut frame_count: u64 = 0;
+        frame_count += h as u64 * 60 * 60 * max_frame;
+        frame_count += m as u64 * 60 * max_frame;
+        frame_count += s as u64 * max_frame;
+        frame_count += f as u64;
+
+        if let Some(drop_frames) = self.drop_frames() {
+            let minute_count = h as u64 * 60 + m as u64;
+            //every 10 minutes, we /dont/ skip a frame. so count the number of times
+            //that happens.
+            let dropskip_count = minute_count / 10;
+            //frame_count is at least minute_count * 60 * max_frame, and drop_frames is
+            //max_frame / 15, so this never goes below zero
+            frame_count -= (minute_count - dropskip_count) * drop_frames as u64;
+
+            //a dropped address counts as the last real frame before it
+            if s == 0 && f < drop_frames && !minute_count.is_multiple_of(10) {
+                frame_count += (drop_frames - f - 1) as u64;
+            }
+        }
+
+        frame_count.try_into().unwrap_or(FrameCount::MAX)
+    }
 }
 
 pub trait ConstFramerate {
@@ -37,10 +94,10 @@ const fn is_valid_df_count(frames: FrameCount) -> bool {
 }
 
 ///Dropframe timecode, with framerate stored at compile-time. Must be multiple of 30.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub struct DF<const FRAMES: FrameCount>;
 ///Non-drop timecode, with framerate stored at compile-time.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub struct NDF<const FRAMES: FrameCount>;
 
 impl<const FRAMES: FrameCount> ConstFramerate for NDF<FRAMES> {
@@ -75,8 +132,8 @@ impl<const FRAMES: FrameCount> crate::Framerate for NDF<FRAMES> {
         None
     }
 
-    fn fr_ratio(&self) -> f32 {
-        self.fr_num() as f32 / self.fr_denom() as f32
+    fn fr_ratio(&self) -> f64 {
+        self.fr_num() as f64 / self.fr_denom() as f64
     }
 
     fn fr_num(&self) -> u64 {
@@ -101,8 +158,8 @@ impl<const FRAMES: FrameCount> crate::Framerate for DF<FRAMES> {
         Some(FRAMES / 15) //30 = 2, 60 = 4, etc,
     }
 
-    fn fr_ratio(&self) -> f32 {
-        self.fr_num() as f32 / self.fr_denom() as f32
+    fn fr_ratio(&self) -> f64 {
+        self.fr_num() as f64 / self.fr_denom() as f64
     }
 
     fn fr_num(&self) -> u64 {
@@ -119,7 +176,7 @@ where
     F: ConstFramerate + Framerate,
 {
     fn from(s: &F) -> DynFramerate {
-        DynFramerate::new(s.max_frame(), s.drop_frames().is_some()).unwrap()
+        DynFramerate::from_framerate(s)
     }
 }
 
@@ -157,7 +214,7 @@ impl<const FRAMES: FrameCount> TryFrom<DynFramerate> for DF<FRAMES> {
 }
 
 ///A framerate stored at runtime. can be either DF or NDF.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub struct DynFramerate {
     count: FrameCount,
     is_df: bool,
@@ -197,6 +254,127 @@ impl DynFramerate {
 
         Self { count, is_df: true }
     }
+
+    ///Build a `DynFramerate` from any other framerate, including ones only known through generic
+    ///code.
+    ///
+    ///```
+    ///# use timecode::framerates::*;
+    ///let fr = DynFramerate::from_framerate(&DF::<30>);
+    ///assert_eq!(fr, DynFramerate::new_df(30));
+    ///```
+    pub fn from_framerate(fr: &impl Framerate) -> Self {
+        Self::new(fr.max_frame(), fr.drop_frames().is_some()).unwrap()
+    }
+
+    ///A short string naming this framerate which [`FromStr`](std::str::FromStr) parses back into
+    ///the same framerate. Together with a frame count, this is enough to rebuild a timecode
+    ///without re-parsing the timecode string.
+    ///
+    ///```
+    ///# use timecode::framerates::*;
+    ///assert_eq!(DynFramerate::new_ndf(25).to_tag(), "25");
+    ///assert_eq!(DynFramerate::new_df(30).to_tag(), "29.97");
+    ///assert_eq!(DynFramerate::new_df(60).to_tag(), "59.94");
+    ///```
+    pub fn to_tag(&self) -> String {
+        self.to_string()
+    }
+
+    ///Number of characters in the widest timecode before `24:00:00:00` at this framerate,
+    ///for sizing table columns. Framerates of 101fps and above need three frame digits.
+    ///
+    ///```
+    ///# use timecode::framerates::*;
+    ///assert_eq!(DynFramerate::new_ndf(30).display_width(), "23:59:59:29".len());
+    ///assert_eq!(DynFramerate::new_ndf(120).display_width(), "23:59:59:119".len());
+    ///```
+    pub fn display_width(&self) -> usize {
+        "00:00:00:".len() + crate::parser::frame_digits(self)
+    }
+
+    ///Parse a framerate written as a whole number slowed down by 0.1%, such as `"24 -0.1%"` or
+    ///`"30 @ 0.1% slow"`.
+    ///
+    ///Multiples of 30 become dropframe (`30000/1001`). [`DynFramerate`] can't hold a non-drop
+    ///x/1001 rate, so anything else gives the same framerate as
+    ///[`DynFramerate::from_rational`]: `"24 -0.1%"` counts whole frames at 24 non-drop, the same
+    ///way `"23.98"` and `24000/1001` do, and its exact 24000/1001 rate is not kept.
+    ///
+    ///```
+    ///# use timecode::framerates::*;
+    ///let fr = DynFramerate::from_pulldown_str("30 -0.1%").unwrap();
+    ///assert_eq!((fr.fr_num(), fr.fr_denom()), (30000, 1001));
+    ///
+    ///let film = DynFramerate::from_pulldown_str("24 -0.1%").unwrap();
+    ///assert_eq!(Some(film), DynFramerate::from_rational(24000, 1001));
+    ///```
+    pub fn from_pulldown_str(s: &str) -> Result<Self, &'static str> {
+        let (base, pulldown) = s
+            .trim()
+            .split_once(char::is_whitespace)
+            .ok_or("Missing pulldown")?;
+
+        let pulldown: Vec<_> = pulldown.split_whitespace().collect();
+        if pulldown != ["-0.1%"] && pulldown != ["@", "0.1%", "slow"] {
+            return Err("Unknown pulldown");
+        }
+
+        let count: FrameCount = base.parse().map_err(|_| "Invalid framerate")?;
+        if count == 0 {
+            return Err("Invalid framerate");
+        }
+
+        Ok(Self::try_new_df(count).unwrap_or(Self::new_ndf(count)))
+    }
+
+    ///True if both framerates are NTSC-derived (x/1001) or both are whole numbers. Converting
+    ///between families can never be exact.
+    pub fn same_family(&self, other: &DynFramerate) -> bool {
+        self.fr_denom() == other.fr_denom()
+    }
+
+    ///Frame counts within the first hour where dropframe renumbering happens, i.e. the first
+    ///frame of every minute not divisible by 10. Empty for non-drop framerates.
+    ///
+    ///```
+    ///# use timecode::framerates::*;
+    ///let boundaries = DynFramerate::new_df(30).known_boundary_frames();
+    ///assert_eq!(&boundaries[..3], &[1800, 3598, 5396]);
+    ///assert!(DynFramerate::new_ndf(30).known_boundary_frames().is_empty());
+    ///```
+    pub fn known_boundary_frames(&self) -> Vec<FrameCount> {
+        let drop_frames = match self.drop_frames() {
+            Some(d) => d,
+            None => return vec![],
+        };
+
+        (1..60)
+            .filter(|m| m % 10 != 0)
+            .map(|m| self.frames_for(0, m, 0, drop_frames))
+            .collect()
+    }
+
+    ///For each minute of the hour, the frame numbers that are skipped at its start. Every minute
+    ///not divisible by 10 skips frames `0..drop_frames`, and every other minute (or every minute,
+    ///for non-drop framerates) skips nothing.
+    ///
+    ///```
+    ///# use timecode::framerates::*;
+    ///let schedule = DynFramerate::new_df(30).skip_schedule();
+    ///assert_eq!(schedule[1], (1, vec![0, 1]));
+    ///assert_eq!(schedule[10], (10, vec![]));
+    ///```
+    pub fn skip_schedule(&self) -> Vec<(u8, Vec<FrameCount>)> {
+        let drop_frames = self.drop_frames().unwrap_or(0);
+
+        (0..60)
+            .map(|m| match m % 10 {
+                0 => (m, vec![]),
+                _ => (m, (0..drop_frames).collect()),
+            })
+            .collect()
+    }
 }
 
 impl crate::Framerate for DynFramerate {
@@ -218,8 +396,8 @@ impl crate::Framerate for DynFramerate {
         }
     }
 
-    fn fr_ratio(&self) -> f32 {
-        self.fr_num() as f32 / self.fr_denom() as f32
+    fn fr_ratio(&self) -> f64 {
+        self.fr_num() as f64 / self.fr_denom() as f64
     }
 
     fn fr_num(&self) -> u64 {
@@ -238,13 +416,58 @@ impl crate::Framerate for DynFramerate {
     }
 }
 
-impl std::str::FromStr for DynFramerate {
-    type Err = &'static str;
+///Writes the same string as [`DynFramerate::to_tag`]: `"30"` for 30fps non-drop, `"29.97"` for
+///30fps dropframe, and so on.
+impl std::fmt::Display for DynFramerate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.is_df {
+            true => write!(f, "{:.2}", self.fr_num() as f64 / self.fr_denom() as f64),
+            false => write!(f, "{}", self.count),
+        }
+    }
+}
+
+///How [`DynFramerate::parse_with_reason`] decided on a framerate
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum InferReason {
+    ///The input was a whole number, such as `"25"`
+    ExactInteger,
+    ///The input matched a well known NTSC rate, such as `"29.97"` or `"23.98"`
+    SpecialNtsc,
+    ///The input was a float close enough to a whole number to be rounded, such as `"25.001"`
+    RoundedInteger,
+    ///The input was close to a multiple of 29.97, so a dropframe rate was assumed
+    DropMultiple,
+    ///The input was an exact ratio, such as `"30000/1001"`
+    Rational,
+}
+
+impl DynFramerate {
+    ///Parse a framerate the same way as [`FromStr`](std::str::FromStr), but also report which
+    ///heuristic was used to pick it.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, DynFramerate};
+    ///let (fr, reason) = DynFramerate::parse_with_reason("59.94").unwrap();
+    ///assert_eq!(fr, DynFramerate::new_df(60));
+    ///assert_eq!(reason, InferReason::DropMultiple);
+    ///```
+    pub fn parse_with_reason(s: &str) -> Result<(Self, InferReason), &'static str> {
+        //an exact ratio like 30000/1001
+        if let Some((num, denom)) = s.split_once('/') {
+            let (num, denom) = match (num.trim().parse(), denom.trim().parse()) {
+                (Ok(num), Ok(denom)) => (num, denom),
+                _ => return Err("Invalid framerate ratio"),
+            };
+
+            return Self::from_rational(num, denom)
+                .map(|fr| (fr, InferReason::Rational))
+                .ok_or("Invalid framerate ratio");
+        }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
         //if it can be parsed as an integer, assume it is NDF
         if let Ok(fr) = s.parse() {
-            return Ok(Self::new(fr, false).unwrap());
+            return Ok((Self::new(fr, false).unwrap(), InferReason::ExactInteger));
         }
 
         if let Ok(float) = s.parse::<f64>() {
@@ -252,7 +475,10 @@ impl std::str::FromStr for DynFramerate {
 
             //If it can be parsed as a float, see if it is near a whole number
             if (float - float.round()).abs() < EPISILON {
-                return Ok(Self::new_ndf(float.round() as _));
+                return Ok((
+                    Self::new_ndf(float.round() as _),
+                    InferReason::RoundedInteger,
+                ));
             }
 
             const SPECIAL: &[(f64, DynFramerate)] = &[
@@ -264,14 +490,17 @@ impl std::str::FromStr for DynFramerate {
             //Or if it is a special framerate
             for (fr, s) in SPECIAL {
                 if (float - fr).abs() < EPISILON {
-                    return Ok(*s);
+                    return Ok((*s, InferReason::SpecialNtsc));
                 }
             }
 
             //if we are close to a multiple of 29.97, use dropframe
             let k = float / 29.97;
             if (k - k.round()).abs() < EPISILON {
-                return Ok(Self::try_new_df((k.round() as FrameCount) * 30).unwrap());
+                return Ok((
+                    Self::try_new_df((k.round() as FrameCount) * 30).unwrap(),
+                    InferReason::DropMultiple,
+                ));
             }
         }
 
@@ -279,6 +508,53 @@ impl std::str::FromStr for DynFramerate {
     }
 }
 
+impl DynFramerate {
+    ///Build a framerate from `num / denom` frames per second, such as the rational stored in
+    ///container metadata. A denominator of 1001 is an NTSC rate, which is dropframe when `num` is
+    ///a multiple of 30000. Any other ratio must be a whole number. Returns `None` for ratios that
+    ///are not a framerate, like a zero denominator.
+    ///
+    ///```
+    ///# use timecode::framerates::*;
+    ///assert_eq!(DynFramerate::from_rational(30000, 1001), Some(DynFramerate::new_df(30)));
+    ///assert_eq!(DynFramerate::from_rational(24000, 1001), Some(DynFramerate::new_ndf(24)));
+    ///assert_eq!(DynFramerate::from_rational(25, 1), Some(DynFramerate::new_ndf(25)));
+    ///assert_eq!(DynFramerate::from_rational(25, 0), None);
+    ///```
+    pub fn from_rational(num: u64, denom: u64) -> Option<Self> {
+        if num == 0 || denom == 0 {
+            return None;
+        }
+
+        if denom == 1001 {
+            if !num.is_multiple_of(1000) {
+                return None;
+            }
+
+            let count = FrameCount::try_from(num / 1000).ok()?;
+            if num.is_multiple_of(30000) {
+                return Self::try_new_df(count);
+            }
+
+            return Some(Self::new_ndf(count));
+        }
+
+        if !num.is_multiple_of(denom) {
+            return None;
+        }
+
+        FrameCount::try_from(num / denom).ok().map(Self::new_ndf)
+    }
+}
+
+impl std::str::FromStr for DynFramerate {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_reason(s).map(|(fr, _)| fr)
+    }
+}
+
 #[cfg(test)]
 mod read_dyn_framerates {
     use crate::DynFramerate;
@@ -307,6 +583,119 @@ mod read_dyn_framerates {
         assert_eq!(s, DynFramerate::new_ndf(24));
     }
 
+    #[test]
+    fn pulldown_str() {
+        use crate::Framerate;
+
+        let fr = DynFramerate::from_pulldown_str("30 -0.1%").unwrap();
+        assert_eq!((fr.fr_num(), fr.fr_denom()), (30000, 1001));
+        assert_eq!(
+            DynFramerate::from_pulldown_str("60 @ 0.1% slow"),
+            Ok(DynFramerate::new_df(60))
+        );
+
+        //24000/1001 is stored as 24 non-drop, like "23.98"
+        let film = DynFramerate::from_pulldown_str("24 -0.1%").unwrap();
+        assert_eq!(Some(film), DynFramerate::from_rational(24000, 1001));
+        assert_eq!(film, "23.98".parse::<DynFramerate>().unwrap());
+        assert_eq!(
+            DynFramerate::from_pulldown_str("25 @ 0.1% slow"),
+            Ok(DynFramerate::new_ndf(25))
+        );
+
+        assert!(DynFramerate::from_pulldown_str("24").is_err());
+        assert!(DynFramerate::from_pulldown_str("24 +0.1%").is_err());
+        assert!(DynFramerate::from_pulldown_str("0 -0.1%").is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(DynFramerate::new_ndf(30).to_string(), "30");
+        assert_eq!(DynFramerate::new_df(30).to_string(), "29.97");
+        assert_eq!(DynFramerate::new_df(60).to_string(), "59.94");
+
+        for fr in [
+            DynFramerate::new_ndf(24),
+            DynFramerate::new_df(30),
+            DynFramerate::new_df(60),
+            DynFramerate::new_df(120),
+        ] {
+            assert_eq!(fr.to_string().parse::<DynFramerate>(), Ok(fr));
+        }
+    }
+
+    #[test]
+    fn read_with_reason() {
+        use super::InferReason;
+
+        let cases = [
+            ("25", DynFramerate::new_ndf(25), InferReason::ExactInteger),
+            ("29.97", DynFramerate::new_df(30), InferReason::SpecialNtsc),
+            (
+                "25.001",
+                DynFramerate::new_ndf(25),
+                InferReason::RoundedInteger,
+            ),
+            ("59.94", DynFramerate::new_df(60), InferReason::DropMultiple),
+        ];
+
+        for (input, fr, reason) in cases {
+            assert_eq!(DynFramerate::parse_with_reason(input), Ok((fr, reason)));
+        }
+    }
+
+    #[test]
+    fn read_rational() {
+        use super::InferReason;
+
+        let s: DynFramerate = "30000/1001".parse().unwrap();
+        assert_eq!(s, DynFramerate::new_df(30));
+        let s: DynFramerate = "60000/1001".parse().unwrap();
+        assert_eq!(s, DynFramerate::new_df(60));
+        let s: DynFramerate = "24000/1001".parse().unwrap();
+        assert_eq!(s, DynFramerate::new_ndf(24));
+        let s: DynFramerate = "25/1".parse().unwrap();
+        assert_eq!(s, DynFramerate::new_ndf(25));
+        let s: DynFramerate = "50/2".parse().unwrap();
+        assert_eq!(s, DynFramerate::new_ndf(25));
+
+        assert_eq!(
+            DynFramerate::parse_with_reason("30000/1001"),
+            Ok((DynFramerate::new_df(30), InferReason::Rational))
+        );
+
+        assert!("0/1001".parse::<DynFramerate>().is_err());
+        assert!("25/0".parse::<DynFramerate>().is_err());
+        assert!("25/2".parse::<DynFramerate>().is_err());
+        assert!("30000/".parse::<DynFramerate>().is_err());
+        assert!("a/1001".parse::<DynFramerate>().is_err());
+    }
+
+    #[test]
+    fn from_rational() {
+        use crate::Framerate;
+
+        for fr in [
+            DynFramerate::new_ndf(24),
+            DynFramerate::new_ndf(25),
+            DynFramerate::new_ndf(120),
+            DynFramerate::new_df(30),
+            DynFramerate::new_df(60),
+            DynFramerate::new_df(120),
+        ] {
+            assert_eq!(
+                DynFramerate::from_rational(fr.fr_num(), fr.fr_denom()),
+                Some(fr)
+            );
+        }
+
+        assert_eq!(DynFramerate::from_rational(0, 1), None);
+        assert_eq!(DynFramerate::from_rational(0, 1001), None);
+        assert_eq!(DynFramerate::from_rational(30, 0), None);
+        assert_eq!(DynFramerate::from_rational(30001, 1001), None);
+        assert_eq!(DynFramerate::from_rational(u64::MAX, 1), None);
+    }
+
     #[test]
     fn read_fr_high() {
         let s: DynFramerate = "239.99".parse().unwrap();
@@ -318,6 +707,21 @@ mod read_dyn_framerates {
         let s: DynFramerate = "239.76".parse().unwrap();
         assert_eq!(s, DynFramerate::new_df(240));
     }
+
+    #[test]
+    fn tag_round_trip() {
+        for fr in [
+            DynFramerate::new_ndf(24),
+            DynFramerate::new_ndf(25),
+            DynFramerate::new_ndf(120),
+            DynFramerate::new_df(30),
+            DynFramerate::new_df(60),
+            DynFramerate::new_df(120),
+        ] {
+            let s: DynFramerate = fr.to_tag().parse().unwrap();
+            assert_eq!(s, fr);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -326,6 +730,64 @@ mod construct_framerates {
 
     use super::*;
 
+    #[test]
+    fn fr_ratio_precision() {
+        assert_eq!(DF::<60>.fr_ratio(), 60000.0 / 1001.0);
+        assert_eq!(DynFramerate::new_df(30).fr_ratio(), 30000.0 / 1001.0);
+        assert_eq!(NDF::<25>.fr_ratio(), 25.0);
+    }
+
+    #[test]
+    fn display_width() {
+        use crate::{Frames, Timecode, ToFrames};
+
+        assert_eq!(DynFramerate::new_ndf(30).display_width(), 11);
+        assert_eq!(DynFramerate::new_df(60).display_width(), 11);
+        assert_eq!(DynFramerate::new_ndf(100).display_width(), 11);
+        assert_eq!(DynFramerate::new_ndf(120).display_width(), 12);
+        assert_eq!(DynFramerate::new_df(120).display_width(), 12);
+
+        let fr = DynFramerate::new_df(120);
+        let widest = Timecode::from_frames(&Frames(Timecode::max_frame_count_24h(&fr)), &fr);
+        assert_eq!(widest.to_string().len(), fr.display_width());
+    }
+
+    #[test]
+    fn skip_schedule() {
+        let schedule = DynFramerate::new_df(30).skip_schedule();
+        assert_eq!(schedule.len(), 60);
+        assert_eq!(schedule[0], (0, vec![]));
+        assert_eq!(schedule[1], (1, vec![0, 1]));
+        assert_eq!(schedule[10], (10, vec![]));
+        assert_eq!(schedule[59], (59, vec![0, 1]));
+
+        let schedule = DynFramerate::new_df(60).skip_schedule();
+        assert_eq!(schedule[1], (1, vec![0, 1, 2, 3]));
+
+        let schedule = DynFramerate::new_ndf(30).skip_schedule();
+        assert!(schedule.iter().all(|(_, skipped)| skipped.is_empty()));
+
+        //every skipped address is rejected by validation
+        for (m, skipped) in DynFramerate::new_df(30).skip_schedule() {
+            for f in skipped {
+                let tc = crate::unvalidated(&format!("00:{m:02}:00;{f:02}")).unwrap();
+                assert!(tc.validate::<DF<30>>().is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn legal_separators() {
+        assert_eq!(DF::<60>.legal_separators(), &[';']);
+        assert_eq!(NDF::<25>.legal_separators(), &[':', '.']);
+        assert_eq!(DynFramerate::new_df(30).legal_separators(), &[';']);
+        assert_eq!(DynFramerate::new_ndf(24).legal_separators(), &[':', '.']);
+
+        for fr in [DynFramerate::new_df(30), DynFramerate::new_ndf(30)] {
+            assert!(fr.legal_separators().contains(&fr.to_sep()));
+        }
+    }
+
     #[test]
     fn make_simple() {
         let _ = DynFramerate::new_df(30);
@@ -360,6 +822,72 @@ mod construct_framerates {
         }
     }
 
+    #[test]
+    fn from_framerate() {
+        assert_eq!(
+            DynFramerate::from_framerate(&DF::<30>),
+            DynFramerate::new_df(30)
+        );
+        assert_eq!(
+            DynFramerate::from_framerate(&NDF::<25>),
+            DynFramerate::new_ndf(25)
+        );
+
+        let d = DynFramerate::new_df(60);
+        assert_eq!(DynFramerate::from_framerate(&d), d);
+    }
+
+    #[test]
+    fn same_family() {
+        let df30 = DynFramerate::new_df(30);
+        let df60 = DynFramerate::new_df(60);
+        let ndf25 = DynFramerate::new_ndf(25);
+        let ndf30 = DynFramerate::new_ndf(30);
+
+        assert!(df30.same_family(&df60));
+        assert!(ndf25.same_family(&ndf30));
+        assert!(!df30.same_family(&ndf25));
+        assert!(!df30.same_family(&ndf30));
+    }
+
+    #[test]
+    fn frames_for_matches_timecode() {
+        use crate::{Timecode, ToFrames};
+
+        for input in ["00:00:00;00", "00:01:00;02", "00:10:00;00", "13:27:41;17"] {
+            let tc: Timecode<DF<30>> = input.parse().unwrap();
+            let count = DF::<30>.frames_for(tc.h(), tc.m(), tc.s(), tc.f());
+            assert_eq!(count, tc.to_frame_count());
+
+            let fr = DynFramerate::new_df(30);
+            assert_eq!(fr.frames_for(tc.h(), tc.m(), tc.s(), tc.f()), count);
+        }
+
+        for input in ["00:00:00:00", "00:01:00:00", "23:59:59:24"] {
+            let tc: Timecode<NDF<25>> = input.parse().unwrap();
+            let count = NDF::<25>.frames_for(tc.h(), tc.m(), tc.s(), tc.f());
+            assert_eq!(count, tc.to_frame_count());
+        }
+    }
+
+    #[test]
+    fn boundary_frames_renumber() {
+        use crate::{Frames, Timecode, ToFrames};
+
+        let fr = DynFramerate::new_df(30);
+        let boundaries = fr.known_boundary_frames();
+        assert_eq!(boundaries.len(), 54);
+
+        for b in boundaries {
+            let before = Timecode::from_frames(&Frames(b - 1), &fr);
+            let at = Timecode::from_frames(&Frames(b), &fr);
+
+            assert_eq!((before.s(), before.f()), (59, 29));
+            assert_eq!((at.m(), at.s(), at.f()), (before.m() + 1, 0, 2));
+            assert_ne!(at.m() % 10, 0);
+        }
+    }
+
     #[test]
     fn make_dyn() {
         let s = NDF::<30>;
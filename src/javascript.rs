@@ -1,6 +1,9 @@
 use wasm_bindgen::prelude::*;
 
-use crate::{Convert, DynFramerate, FrameCount, Framerate, Frames, Timecode, ToFrames};
+use crate::{
+    validate::TimecodeValidationError, Convert, DynFramerate, FrameCount, Framerate, Frames,
+    Timecode, ToFrames,
+};
 
 ///An immutable timecode object
 #[wasm_bindgen(js_name = "Timecode")]
@@ -16,6 +19,23 @@ impl JSTimecode {
         Ok(Self(f))
     }
 
+    ///Construct a new timecode from timecode and framerate, also returning any warnings, such as
+    ///a `:` separator on a dropframe timecode.
+    pub fn new_checked(timecode: &str, framerate: &str) -> Result<CheckedTimecode, JsValue> {
+        let d: DynFramerate = framerate.parse().map_err(|e: &str| JsValue::from_str(e))?;
+        let unvalidated = crate::unvalidated(timecode)
+            .ok_or_else(|| JsValue::from_str(&TimecodeValidationError::Unparsed.to_string()))?;
+
+        let (tc, warnings) = unvalidated
+            .validate_with_warnings_fr(&d)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(CheckedTimecode {
+            tc,
+            warnings: warnings.iter().map(|w| w.to_string()).collect(),
+        })
+    }
+
     ///Construct a new timecode from frame count and framerate
     pub fn from_frames(frames: FrameCount, framerate: &str) -> Result<JSTimecode, JsValue> {
         let d: DynFramerate = framerate.parse().map_err(|e: &str| JsValue::from_str(e))?;
@@ -33,18 +53,61 @@ impl JSTimecode {
         JSTimecode(self.0 + tc.0)
     }
 
-    ///Advance this timecode forward by frames
-    pub fn add_frames(&self, frames: FrameCount) -> JSTimecode {
-        JSTimecode(self.0 + Frames(frames))
+    ///Advance this timecode forward by frames. Throws an exception if the frame count would
+    ///overflow.
+    pub fn add_frames(&self, frames: FrameCount) -> Result<JSTimecode, JsValue> {
+        self.0
+            .add_frames(Frames(frames))
+            .map(JSTimecode)
+            .map_err(|_| JsValue::from_str("Too many frames"))
     }
 
     ///Move this timecode backward by frames. Throws an exception if timecode would go below 0
     ///frames.
     pub fn sub_frames(&self, frames: FrameCount) -> Result<JSTimecode, JsValue> {
-        if self.0.to_frame_count() < frames {
-            return Err(JsValue::from_str("Not enough frames"));
+        self.0
+            .sub_frames(Frames(frames))
+            .map(JSTimecode)
+            .map_err(|_| JsValue::from_str("Not enough frames"))
+    }
+
+    ///Move this timecode backward by frames, stopping at 00:00:00:00 instead of throwing.
+    pub fn sub_frames_saturating(&self, frames: FrameCount) -> JSTimecode {
+        JSTimecode(self.0.saturating_sub_frames(Frames(frames)))
+    }
+
+    ///True if the two timecodes are within `frames` frames of each other in real time, counted at
+    ///the slower framerate.
+    #[wasm_bindgen(js_name = "closeTo")]
+    pub fn close_to(&self, other: &JSTimecode, frames: FrameCount) -> bool {
+        self.0.close_to(&other.0, frames)
+    }
+
+    ///True if both timecodes have the same fields and framerate
+    #[wasm_bindgen(js_name = "eq")]
+    pub fn equals(&self, other: &JSTimecode) -> bool {
+        self.0 == other.0
+    }
+
+    ///True if this timecode is before `other`. Throws an exception if the framerates differ.
+    pub fn lt(&self, other: &JSTimecode) -> Result<bool, JsValue> {
+        Ok(self.compare(other)? < 0)
+    }
+
+    ///True if this timecode is after `other`. Throws an exception if the framerates differ.
+    pub fn gt(&self, other: &JSTimecode) -> Result<bool, JsValue> {
+        Ok(self.compare(other)? > 0)
+    }
+
+    ///Compare by frame count, returning -1, 0, or 1 so it can be passed to `Array.sort`. Throws
+    ///an exception if the framerates differ.
+    #[wasm_bindgen(js_name = "cmp")]
+    pub fn compare(&self, other: &JSTimecode) -> Result<i32, JsValue> {
+        if self.0.framerate() != other.0.framerate() {
+            return Err(JsValue::from_str("Framerate mismatch"));
         }
-        Ok(JSTimecode(self.0 - Frames(frames)))
+
+        Ok(self.0.to_frame_count().cmp(&other.0.to_frame_count()) as i32)
     }
 
     ///Return the number of frames since 00:00:00:00
@@ -52,6 +115,16 @@ impl JSTimecode {
         self.0.to_frame_count()
     }
 
+    ///Real seconds since 00:00:00:00. Dropframe timecodes use the 29.97fps wall clock.
+    pub fn total_seconds(&self) -> f64 {
+        self.0.as_seconds_f64()
+    }
+
+    ///Real milliseconds since 00:00:00:00. See `total_seconds`.
+    pub fn total_ms(&self) -> f64 {
+        self.0.as_seconds_f64() * 1000.0
+    }
+
     ///Convert timecode to another framerate, with 00:00:00:00 as the basis
     pub fn convert_to(&self, framerate: &str) -> Result<JSTimecode, JsValue> {
         let d: DynFramerate = framerate.parse().map_err(|e: &str| JsValue::from_str(e))?;
@@ -97,6 +170,28 @@ impl JSTimecode {
     }
 }
 
+///A timecode along with the warnings produced while validating it
+#[wasm_bindgen]
+pub struct CheckedTimecode {
+    tc: Timecode<DynFramerate>,
+    warnings: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl CheckedTimecode {
+    ///The validated timecode
+    #[wasm_bindgen(getter)]
+    pub fn timecode(&self) -> JSTimecode {
+        JSTimecode(self.tc)
+    }
+
+    ///Warnings such as "separator mismatch"
+    #[wasm_bindgen(getter)]
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.clone()
+    }
+}
+
 #[wasm_bindgen]
 extern "C" {
     #[allow(non_camel_case_types)]
@@ -81,52 +81,338 @@
 
 use std::{convert::TryInto, fmt::Display, str::FromStr};
 
+pub mod compact;
+pub mod film;
 pub mod framerates;
 #[cfg(feature = "javascript")]
 pub mod javascript;
+pub mod multiday;
 pub mod parser;
 #[cfg(feature = "python")]
 pub mod python;
+pub mod range;
+pub mod signed;
+pub mod timeline;
 pub mod validate;
 
 pub use framerates::*;
 pub use parser::unvalidated;
+pub use range::TimecodeRange;
 pub use validate::ValidateableFramerate;
 
 use validate::TimecodeValidationError;
 
 //24 hours * 60 * 60 * 120 still has lots of room in a u32
+///Frame counts and frame numbers are stored as a `u32`. See [`Timecode::max_frame_count_24h`] for
+///the largest count within a day at a given framerate.
 pub type FrameCount = u32;
 
 #[derive(Copy, Debug, Eq, PartialEq, Clone)]
 #[repr(transparent)]
 pub struct Frames(pub FrameCount);
 
-#[derive(Copy, Debug, Eq, PartialEq, Clone)]
+#[derive(Copy, Debug, Clone)]
 pub struct Timecode<FR> {
     h: u8,
     m: u8,
     s: u8,
     f: FrameCount,
     framerate: FR,
+    ///Separator to display instead of the framerate's own. See [`Timecode::with_separator`].
+    sep_override: Option<parser::Seperator>,
+}
+
+///The separator override is only used for display, so it does not affect equality or hashing.
+impl<FR: PartialEq> PartialEq for Timecode<FR> {
+    fn eq(&self, other: &Self) -> bool {
+        self.h == other.h
+            && self.m == other.m
+            && self.s == other.s
+            && self.f == other.f
+            && self.framerate == other.framerate
+    }
+}
+
+impl<FR: Eq> Eq for Timecode<FR> {}
+
+///Hashes the same fields that [`PartialEq`] compares, so timecodes can be used as `HashMap` keys.
+///Like `Eq`, hashes are only meaningful between timecodes of the same type: a `Timecode<DF<30>>`
+///and an equal `Timecode<DynFramerate>` do not hash the same.
+impl<FR: std::hash::Hash> std::hash::Hash for Timecode<FR> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.h.hash(state);
+        self.m.hash(state);
+        self.s.hash(state);
+        self.f.hash(state);
+        self.framerate.hash(state);
+    }
+}
+
+impl<FR: Framerate> Timecode<FR> {
+    ///A 64-bit FNV-1a hash of the frame count and framerate. Unlike [`Hash`](std::hash::Hash),
+    ///this value is stable between runs and builds, so it can be used as a persistent cache key.
+    ///Timecodes of the same framerate which are equal have the same fingerprint.
+    pub fn fingerprint(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+
+        let fr = self.framerate();
+        let bytes = self
+            .to_frame_count()
+            .to_le_bytes()
+            .into_iter()
+            .chain(fr.fr_num().to_le_bytes())
+            .chain(fr.fr_denom().to_le_bytes())
+            .chain([fr.is_dropframe() as u8]);
+
+        bytes.fold(FNV_OFFSET, |hash, b| {
+            (hash ^ b as u64).wrapping_mul(FNV_PRIME)
+        })
+    }
+
+    ///The timecode closest to `ms` milliseconds of real time after `00:00:00:00`. Use
+    ///[`parser::parse_ms`] to read a `HH:MM:SS.mmm` timestamp.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, parser::parse_ms};
+    ///let (h, m, s, ms) = parse_ms("00:00:01.999").unwrap();
+    ///let total = ((h as u64 * 60 + m as u64) * 60 + s as u64) * 1000 + ms as u64;
+    ///
+    ///let tc = Timecode::from_ms(total, &NDF::<25>);
+    ///assert_eq!(tc.to_string(), "00:00:02:00");
+    ///```
+    pub fn from_ms(ms: u64, fr: &FR) -> Timecode<FR> {
+        Timecode::from_duration(std::time::Duration::from_millis(ms), fr)
+    }
+
+    ///The real time of this frame as `HH:MM:SS.mmm`, rounded to the nearest millisecond. For
+    ///dropframe timecodes this is the 29.97fps wall clock time, so it will not match the
+    ///timecode's own hours, minutes, and seconds.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<NDF<25>> = "01:02:03:12".parse().unwrap();
+    ///assert_eq!(tc.to_ms_string(), "01:02:03.480");
+    ///```
+    pub fn to_ms_string(&self) -> String {
+        let ms = (self.to_duration().as_nanos() + 500_000) / 1_000_000;
+        let (s, ms) = (ms / 1000, ms % 1000);
+
+        format!("{:02}:{:02}:{:02}.{:03}", s / 3600, s / 60 % 60, s % 60, ms)
+    }
+
+    ///Pack this timecode and its framerate into one integer, for storing in a database column.
+    ///Values with the same framerate sort by frame count.
+    ///
+    ///| bits    | contents                          |
+    ///|---------|-----------------------------------|
+    ///| 0..32   | frame count                       |
+    ///| 32..48  | frames per second (`max_frame`)   |
+    ///| 48      | 1 if dropframe                    |
+    ///| 49..64  | always 0                          |
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+    ///let packed = tc.to_db_i64();
+    ///assert_eq!(packed, (1 << 48) | (30 << 32) | 107892);
+    ///assert_eq!(Timecode::from_db_i64(packed).unwrap(), tc);
+    ///```
+    ///
+    ///PANIC: if the framerate has more than 65535 frames per second
+    pub fn to_db_i64(&self) -> i64 {
+        let fr = self.framerate();
+        let count: u16 = fr.max_frame().try_into().expect("Too large");
+
+        ((fr.is_dropframe() as i64) << 48) | ((count as i64) << 32) | self.to_frame_count() as i64
+    }
+
+    ///Real time elapsed since `00:00:00:00`, rounded down to the nanosecond. For dropframe
+    ///timecodes this is the 29.97fps wall clock time, not the time shown on the timecode.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///# use std::time::Duration;
+    ///let tc: Timecode<NDF<25>> = "00:00:01:05".parse().unwrap();
+    ///assert_eq!(tc.to_duration(), Duration::from_millis(1200));
+    ///
+    ///let tc: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+    ///assert_eq!(tc.to_duration(), Duration::from_nanos(3_599_996_400_000));
+    ///```
+    pub fn to_duration(&self) -> std::time::Duration {
+        let fr = self.framerate();
+        let nanos = self.to_frame_count() as u128 * fr.fr_denom() as u128 * 1_000_000_000
+            / fr.fr_num() as u128;
+
+        std::time::Duration::from_nanos(nanos as u64)
+    }
+
+    ///Real seconds elapsed since `00:00:00:00`, as a float. See [`Timecode::to_duration`].
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<NDF<25>> = "00:00:01:05".parse().unwrap();
+    ///assert_eq!(tc.as_seconds_f64(), 1.2);
+    ///```
+    pub fn as_seconds_f64(&self) -> f64 {
+        let fr = self.framerate();
+        self.to_frame_count() as f64 * fr.fr_denom() as f64 / fr.fr_num() as f64
+    }
+
+    ///The timecode closest to `d` of real time after `00:00:00:00`. This is the inverse of
+    ///[`Timecode::to_duration`].
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///# use std::time::Duration;
+    ///let tc = Timecode::from_duration(Duration::from_millis(1210), &NDF::<25>);
+    ///assert_eq!(tc.to_string(), "00:00:01:05");
+    ///```
+    ///
+    ///PANIC: if the frame count does not fit in a [`FrameCount`]
+    pub fn from_duration(d: std::time::Duration, fr: &FR) -> Timecode<FR> {
+        let scale = fr.fr_denom() as u128 * 1_000_000_000;
+        let count = (d.as_nanos() * fr.fr_num() as u128 + scale / 2) / scale;
+
+        Timecode::from_frames(&Frames(count.try_into().expect("Too large")), fr)
+    }
+
+    ///Audio sample offset of this timecode at `sample_rate`, rounded to the nearest sample. Audio
+    ///runs in real time, so dropframe timecodes use the 29.97fps wall clock.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<NDF<25>> = "00:00:01:05".parse().unwrap();
+    ///assert_eq!(tc.to_samples(48000), 57600);
+    ///```
+    pub fn to_samples(&self, sample_rate: u32) -> u64 {
+        let fr = self.framerate();
+        let num = self.to_frame_count() as u128 * fr.fr_denom() as u128 * sample_rate as u128;
+        let denom = fr.fr_num() as u128;
+
+        ((num + denom / 2) / denom) as u64
+    }
+
+    ///The timecode of the frame nearest to audio sample `samples` at `sample_rate`. This is the
+    ///inverse of [`Timecode::to_samples`].
+    ///
+    ///PANIC: if `sample_rate` is zero, or the frame count does not fit in a [`FrameCount`]
+    pub fn from_samples(samples: u64, sample_rate: u32, fr: &FR) -> Timecode<FR> {
+        let num = samples as u128 * fr.fr_num() as u128;
+        let denom = fr.fr_denom() as u128 * sample_rate as u128;
+        let count = (num + denom / 2) / denom;
+
+        Timecode::from_frames(&Frames(count.try_into().expect("Too large")), fr)
+    }
 }
 
 impl<FR: Framerate> Display for Timecode<FR> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let sep = self
+            .sep_override
+            .map_or(self.framerate.to_sep(), char::from);
+        self.fmt_with_sep(f, sep)
+    }
+}
+
+impl<FR> Timecode<FR> {
+    fn fmt_with_sep(&self, f: &mut std::fmt::Formatter<'_>, sep: char) -> std::fmt::Result {
         write!(
             f,
             "{:02}:{:02}:{:02}{}{:02}",
-            self.h,
-            self.m,
-            self.s,
-            self.framerate.to_sep(),
-            self.f
-        )?;
-        Ok(())
+            self.h, self.m, self.s, sep, self.f
+        )
+    }
+}
+
+///Which character goes before the frames field when displaying a timecode. Different NLEs expect
+///different conventions when importing.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SeparatorStyle {
+    ///`:` for non-drop, `;` for dropframe. This is what [`Display`] uses.
+    ColonSemicolon,
+    ///`:` for non-drop, `,` for dropframe.
+    ColonComma,
+    ///`:` for everything.
+    AllColon,
+}
+
+impl SeparatorStyle {
+    fn sep(self, is_dropframe: bool) -> char {
+        match (self, is_dropframe) {
+            (Self::ColonSemicolon, true) => ';',
+            (Self::ColonComma, true) => ',',
+            _ => ':',
+        }
+    }
+}
+
+///Returned by [`Timecode::display_with_style`]
+pub struct StyledTimecode<'a, FR> {
+    tc: &'a Timecode<FR>,
+    style: SeparatorStyle,
+}
+
+impl<FR: Framerate> Display for StyledTimecode<'_, FR> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sep = self.style.sep(self.tc.framerate.is_dropframe());
+        self.tc.fmt_with_sep(f, sep)
+    }
+}
+
+impl<FR: Framerate> Timecode<FR> {
+    ///Display this timecode using a specific separator convention.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, SeparatorStyle};
+    ///let tc: Timecode<DF2997> = "01:00:00;00".parse().unwrap();
+    ///assert_eq!(tc.display_with_style(SeparatorStyle::ColonComma).to_string(), "01:00:00,00");
+    ///```
+    pub fn display_with_style(&self, style: SeparatorStyle) -> StyledTimecode<'_, FR> {
+        StyledTimecode { tc: self, style }
     }
 }
 
 impl<FR> Timecode<FR> {
+    ///Build a timecode directly from its fields. Callers must have already checked the fields are
+    ///valid for `framerate`.
+    pub(crate) fn from_parts(h: u8, m: u8, s: u8, f: FrameCount, framerate: FR) -> Self {
+        Timecode {
+            h,
+            m,
+            s,
+            f,
+            framerate,
+            sep_override: None,
+        }
+    }
+
+    ///Display this timecode with `sep` before the frames instead of the framerate's usual
+    ///separator. Pass `None` to go back to the default. The override is kept through
+    ///[`Convert`], but timecodes produced by arithmetic use the default separator.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, parser::Seperator, Timecode, Convert};
+    ///let tc: Timecode<NDF<30>> = "01:00:00:00".parse().unwrap();
+    ///let tc = tc.with_separator(Some(Seperator::Semicolon));
+    ///assert_eq!(tc.to_string(), "01:00:00;00");
+    ///
+    ///let converted: Timecode<NDF<25>> = tc.convert();
+    ///assert_eq!(converted.to_string(), "01:00:00;00");
+    ///```
+    ///
+    ///Only separators the parser accepts can be used, so a character has to be converted first:
+    ///
+    ///```compile_fail
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<NDF<30>> = "01:00:00:00".parse().unwrap();
+    ///let tc = tc.with_separator(Some('x'));
+    ///```
+    pub fn with_separator(mut self, sep: Option<parser::Seperator>) -> Self {
+        self.sep_override = sep;
+        self
+    }
+
     pub fn h(&self) -> u8 {
         self.h
     }
@@ -144,6 +430,67 @@ impl<FR> Timecode<FR> {
     }
 }
 
+impl<FR: Framerate> Timecode<FR> {
+    ///`00:00:00:00` at `fr`
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let fr: DynFramerate = "29.97".parse().unwrap();
+    ///assert_eq!(Timecode::zero_with_fr(&fr).to_string(), "00:00:00;00");
+    ///```
+    pub fn zero_with_fr(fr: &FR) -> Self {
+        Timecode::from_parts(0, 0, 0, 0, *fr)
+    }
+
+    ///Same as from_hmsf, but with a dynamic framerate parameter
+    pub fn from_hmsf_with_fr(
+        h: u8,
+        m: u8,
+        s: u8,
+        f: FrameCount,
+        fr: &FR,
+    ) -> Result<Self, TimecodeValidationError> {
+        parser::UnvalidatedTC::for_framerate(h, m, s, f, fr).validate_with_fr(fr)
+    }
+}
+
+impl<FR: Framerate + ConstFramerate> Timecode<FR> {
+    ///`00:00:00:00`. This is also the [`Default`] timecode.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc = Timecode::<NDF<25>>::zero();
+    ///assert_eq!(tc.to_string(), "00:00:00:00");
+    ///assert_eq!(tc, Timecode::default());
+    ///```
+    pub fn zero() -> Self {
+        Self::zero_with_fr(&FR::new())
+    }
+
+    ///Build a timecode from its fields, validating them the same way as
+    ///[`UnvalidatedTC::validate`](parser::UnvalidatedTC::validate).
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, validate::TimecodeValidationError};
+    ///let tc = Timecode::<DF<30>>::from_hmsf(1, 2, 3, 4).unwrap();
+    ///assert_eq!(tc.to_string(), "01:02:03;04");
+    ///
+    ///assert_eq!(
+    ///    Timecode::<DF<30>>::from_hmsf(0, 1, 0, 0),
+    ///    Err(TimecodeValidationError::InvalidFrames(0))
+    ///);
+    ///```
+    pub fn from_hmsf(h: u8, m: u8, s: u8, f: FrameCount) -> Result<Self, TimecodeValidationError> {
+        Self::from_hmsf_with_fr(h, m, s, f, &FR::new())
+    }
+}
+
+impl<FR: Framerate + ConstFramerate> Default for Timecode<FR> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
 impl<FR: validate::ValidateableFramerate + ConstFramerate> FromStr for Timecode<FR> {
     type Err = TimecodeValidationError;
 
@@ -154,13 +501,42 @@ impl<FR: validate::ValidateableFramerate + ConstFramerate> FromStr for Timecode<
     }
 }
 
+///Split `"01:00:00:00@30"` or `"01:00:00:00 30"` into its timecode and framerate parts.
+fn split_tc_fr(s: &str) -> Option<(&str, &str)> {
+    if s.contains('@') {
+        let mut at = s.split('@');
+        return Some((at.next()?, at.next()?));
+    }
+
+    s.trim_end()
+        .rsplit_once(char::is_whitespace)
+        .map(|(tc, fr)| (tc.trim_end(), fr))
+}
+
+///Parses a timecode followed by its framerate, either as `01:00:00:00@30` or separated by
+///whitespace as `01:00:00:00 30`.
+///
+///When there is no `@`, the last whitespace separated word is always taken as the framerate, so
+///any other trailing text (such as a clip name) will make this fail rather than be ignored.
+///
+///The framerate is required, as there is no sensible default: `00:00:00:00` could be 25fps or
+///50fps. A bare timecode fails with [`TimecodeValidationError::Unparsed`]. Use
+///[`Timecode::parse_detailed`] to tell a missing framerate apart from other errors.
+///
+///```
+///# use timecode::{framerates::*, Timecode, validate::TimecodeValidationError};
+///let a: Timecode<DynFramerate> = "01:00:00;00@29.97".parse().unwrap();
+///let b: Timecode<DynFramerate> = "01:00:00;00 29.97".parse().unwrap();
+///assert_eq!(a, b);
+///
+///let bare = "01:00:00;00".parse::<Timecode<DynFramerate>>();
+///assert_eq!(bare, Err(TimecodeValidationError::Unparsed));
+///```
 impl FromStr for Timecode<DynFramerate> {
     type Err = TimecodeValidationError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut at = s.split("@");
-        let tc_part = at.next().ok_or(TimecodeValidationError::Unparsed)?;
-        let fr_part = at.next().ok_or(TimecodeValidationError::Unparsed)?;
+        let (tc_part, fr_part) = split_tc_fr(s).ok_or(TimecodeValidationError::Unparsed)?;
         let tc = unvalidated(tc_part).ok_or(TimecodeValidationError::Unparsed)?;
 
         let d: DynFramerate = fr_part
@@ -171,7 +547,88 @@ impl FromStr for Timecode<DynFramerate> {
     }
 }
 
+///Error returned by [`Timecode::parse_detailed`]
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseDetail {
+    ///There was no `@framerate` (or whitespace separated framerate) after the timecode
+    MissingFramerate,
+    ///The framerate part could not be parsed. Holds the text that was found.
+    BadFramerate(String),
+    ///The timecode part could not be parsed, or is not valid at the framerate
+    BadTimecode(TimecodeValidationError),
+}
+
+impl std::fmt::Display for ParseDetail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDetail::MissingFramerate => write!(f, "Missing framerate"),
+            ParseDetail::BadFramerate(fr) => write!(f, "Invalid Framerate {fr:?}"),
+            ParseDetail::BadTimecode(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDetail {}
+
 impl Timecode<DynFramerate> {
+    ///Keep the same address, but change the framerate. Fails if the address does not exist at
+    ///`fr`, such as frame 29 at 25fps. Unlike [`Convert::convert_with_fr`], the frame count
+    ///and real time will change.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<DynFramerate> = "00:00:00:29@30".parse().unwrap();
+    ///assert!(tc.with_framerate(DynFramerate::new_ndf(25)).is_err());
+    ///
+    ///let tc = tc.with_framerate(DynFramerate::new_ndf(60)).unwrap();
+    ///assert_eq!(tc.to_string(), "00:00:00:29");
+    ///```
+    pub fn with_framerate(self, fr: DynFramerate) -> Result<Self, TimecodeValidationError> {
+        let tc = Timecode::from_hmsf_with_fr(self.h, self.m, self.s, self.f, &fr)?;
+
+        Ok(tc.with_separator(self.sep_override))
+    }
+
+    ///Unpack a value made by [`Timecode::to_db_i64`].
+    pub fn from_db_i64(v: i64) -> Result<Self, TimecodeValidationError> {
+        if v >> 49 != 0 {
+            return Err(TimecodeValidationError::Unparsed);
+        }
+
+        let is_df = (v >> 48) & 1 == 1;
+        let count = ((v >> 32) & 0xFFFF) as FrameCount;
+        let fr = DynFramerate::new(count, is_df)
+            .filter(|_| count > 0)
+            .ok_or(TimecodeValidationError::InvalidFramerate(None))?;
+
+        Ok(Timecode::from_frames(&Frames(v as FrameCount), &fr))
+    }
+
+    ///Same as [`str::parse`](std::primitive::str::parse), but says which part of the input was
+    ///wrong.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, ParseDetail};
+    ///assert_eq!(
+    ///    Timecode::parse_detailed("01:00:00:00@31.5"),
+    ///    Err(ParseDetail::BadFramerate("31.5".to_string())),
+    ///);
+    ///assert_eq!(
+    ///    Timecode::parse_detailed("01:00:00:00"),
+    ///    Err(ParseDetail::MissingFramerate),
+    ///);
+    ///```
+    pub fn parse_detailed(s: &str) -> Result<Self, ParseDetail> {
+        let (tc_part, fr_part) = split_tc_fr(s).ok_or(ParseDetail::MissingFramerate)?;
+        let d: DynFramerate = fr_part
+            .parse()
+            .map_err(|_| ParseDetail::BadFramerate(fr_part.to_string()))?;
+        let tc = unvalidated(tc_part)
+            .ok_or(ParseDetail::BadTimecode(TimecodeValidationError::Unparsed))?;
+
+        tc.validate_with_fr(&d).map_err(ParseDetail::BadTimecode)
+    }
+
     ///Construct a `Timecode<DynFramerate>` with timecode and famerate as string inputs
     pub fn new_with_fr(timecode: &str, framerate: &str) -> Result<Self, TimecodeValidationError> {
         let tc = unvalidated(timecode).ok_or(TimecodeValidationError::Unparsed)?;
@@ -238,6 +695,15 @@ pub trait Convert {
     //type Output<DFR>;
     fn convert<DFR: Framerate + ConstFramerate>(&self) -> Timecode<DFR>;
     fn convert_with_fr<DFR: Framerate>(&self, framerate: &DFR) -> Timecode<DFR>;
+    fn try_convert_with_fr<DFR: Framerate>(
+        &self,
+        framerate: &DFR,
+    ) -> Result<Timecode<DFR>, ConversionError>;
+    fn convert_with_fr_rounded<DFR: Framerate>(
+        &self,
+        framerate: &DFR,
+        mode: RoundMode,
+    ) -> Timecode<DFR>;
     fn convert_with_start<DFR: Framerate + ConstFramerate>(&self, start: &Self) -> Timecode<DFR>;
     fn convert_with_start_fr<DFR: Framerate>(&self, start: &Self, framerate: &DFR)
         -> Timecode<DFR>;
@@ -250,16 +716,37 @@ impl<FR: Framerate> Convert for Timecode<FR> {
     }
 
     fn convert_with_fr<DFR: Framerate>(&self, fr: &DFR) -> Timecode<DFR> {
-        let count = self.to_frame_count() as u64;
-
-        //new frame count = old frame count * new_framerate / old_framerate
-        //new = old * (new_fr_num / new_fr_denom) / (old_fr_num / old_fr_denom)
-        //new = old * (new_fr_num / new_fr_denom) * (old_fr_denom / old_fr_num)
+        self.try_convert_with_fr(fr).expect("Too large")
+    }
 
-        let new_fr = count * fr.fr_num() * self.framerate().fr_denom();
-        let new_fr = new_fr / fr.fr_denom() / self.framerate().fr_num();
+    ///Same as `convert_with_fr`, but returns an error instead of panicking if the converted
+    ///frame count does not fit in a [`FrameCount`].
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, Convert, ConversionError};
+    ///let tc: Timecode<NDF<25>> = "255:00:00:00".parse().unwrap();
+    ///assert_eq!(tc.try_convert_with_fr(&NDF::<5000>), Err(ConversionError::TooLarge));
+    ///```
+    fn try_convert_with_fr<DFR: Framerate>(
+        &self,
+        fr: &DFR,
+    ) -> Result<Timecode<DFR>, ConversionError> {
+        self.try_convert_rounded(fr, RoundMode::Floor)
+    }
 
-        Timecode::from_frames(&Frames(new_fr.try_into().expect("Too large")), fr)
+    ///Same as `convert_with_fr`, but rounds the converted frame count according to `mode`.
+    ///`convert_with_fr` always uses [`RoundMode::Floor`].
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, Convert, RoundMode};
+    ///let tc: Timecode<NDF<25>> = "00:00:00:01".parse().unwrap();
+    ///assert_eq!(tc.convert_with_fr_rounded(&NDF::<30>, RoundMode::Floor).to_string(), "00:00:00:01");
+    ///assert_eq!(tc.convert_with_fr_rounded(&NDF::<30>, RoundMode::Ceil).to_string(), "00:00:00:02");
+    ///```
+    ///
+    ///PANIC: if the converted frame count does not fit in a [`FrameCount`]
+    fn convert_with_fr_rounded<DFR: Framerate>(&self, fr: &DFR, mode: RoundMode) -> Timecode<DFR> {
+        self.try_convert_rounded(fr, mode).expect("Too large")
     }
 
     fn convert_with_start<DFR>(&self, start: &Self) -> Timecode<DFR>
@@ -286,8 +773,200 @@ impl<FR: Framerate> Convert for Timecode<FR> {
 
         let new_start: Timecode<DFR> = start.convert_with_fr(fr);
 
-        new_tc + new_start
+        (new_tc + new_start).with_separator(self.sep_override)
+    }
+}
+
+impl<FR: Framerate> Timecode<FR> {
+    fn try_convert_rounded<DFR: Framerate>(
+        &self,
+        fr: &DFR,
+        mode: RoundMode,
+    ) -> Result<Timecode<DFR>, ConversionError> {
+        let count = self.to_frame_count() as u128;
+
+        //new frame count = old frame count * new_framerate / old_framerate
+        //new = old * (new_fr_num / new_fr_denom) / (old_fr_num / old_fr_denom)
+        //new = old * (new_fr_num / new_fr_denom) * (old_fr_denom / old_fr_num)
+
+        //u128 so that very high target framerates cannot overflow
+        let num = count * fr.fr_num() as u128 * self.framerate().fr_denom() as u128;
+        let denom = fr.fr_denom() as u128 * self.framerate().fr_num() as u128;
+        let new_fr = match mode {
+            RoundMode::Floor => num / denom,
+            RoundMode::Ceil => num.div_ceil(denom),
+            RoundMode::Nearest => {
+                let (q, r) = (num / denom, num % denom);
+                match (2 * r).cmp(&denom) {
+                    std::cmp::Ordering::Less => q,
+                    std::cmp::Ordering::Equal => q + q % 2,
+                    std::cmp::Ordering::Greater => q + 1,
+                }
+            }
+        };
+
+        let new_fr = new_fr.try_into().map_err(|_| ConversionError::TooLarge)?;
+        Ok(Timecode::from_frames(&Frames(new_fr), fr).with_separator(self.sep_override))
+    }
+
+    ///Keep the same frame count, but label it with a different framerate. Unlike
+    ///[`Convert::convert_with_fr`], this does not preserve real time: it is for when the frames
+    ///were counted at the wrong rate to begin with.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<NDF<25>> = "00:00:01:00".parse().unwrap();
+    ///let relabeled = tc.relabel(&NDF::<24>);
+    ///assert_eq!(relabeled.to_string(), "00:00:01:01");
+    ///```
+    pub fn relabel<DFR: Framerate>(&self, fr: &DFR) -> Timecode<DFR> {
+        Timecode::from_frames(&Frames(self.to_frame_count()), fr)
+    }
+
+    ///The address this frame count would have if the framerate were dropframe, keeping the same
+    ///number of frames per second. Returns `None` if that is not a valid dropframe rate.
+    ///
+    ///The framerate type can't change drop-ness, so the result uses a [`DynFramerate`].
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<NDF<30>> = "00:01:00:00".parse().unwrap();
+    ///assert_eq!(tc.as_if_drop().unwrap().to_string(), "00:01:00;02");
+    ///```
+    pub fn as_if_drop(&self) -> Option<Timecode<DynFramerate>> {
+        let fr = DynFramerate::try_new_df(self.framerate().max_frame())?;
+        Some(self.relabel(&fr))
+    }
+
+    ///The address this frame count would have if the framerate were non-drop. See
+    ///[`Timecode::as_if_drop`].
+    pub fn as_if_ndf(&self) -> Timecode<DynFramerate> {
+        self.relabel(&DynFramerate::new_ndf(self.framerate().max_frame()))
+    }
+
+    ///Snap to the nearest frame which lands at exactly the same instant as a frame of
+    ///`other_fr`. With both clocks starting at `00:00:00:00`, frames of 30fps and 25fps line up
+    ///every 6 frames of 30fps (every fifth of a second).
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<NDF<30>> = "00:00:01:04".parse().unwrap();
+    ///assert_eq!(tc.common_grid_instant(&NDF::<25>).to_string(), "00:00:01:06");
+    ///```
+    pub fn common_grid_instant(&self, other_fr: &impl Framerate) -> Timecode<FR> {
+        let fr = self.framerate();
+
+        //frame `a` of self is at a * d1 / n1 seconds, which is a whole frame of other_fr when
+        //a * d1 * n2 / (n1 * d2) is an integer
+        let self_period = fr.fr_num() * other_fr.fr_denom();
+        let other_period = fr.fr_denom() * other_fr.fr_num();
+        let step = self_period / gcd(self_period, other_period);
+
+        let count = self.to_frame_count() as u64;
+        let snapped = (count + step / 2) / step * step;
+
+        Timecode::from_frames(&Frames(snapped.try_into().expect("Too large")), fr)
+    }
+
+    ///Losslessly move to a framerate that is exactly `factor` times this one, such as 25 to 50.
+    ///Each source frame maps to the first of `factor` destination frames.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<NDF<25>> = "01:00:00:12".parse().unwrap();
+    ///let up: Timecode<NDF<50>> = tc.upsample(2);
+    ///assert_eq!(up.to_string(), "01:00:00:24");
+    ///```
+    ///
+    ///PANIC: if the destination framerate is not `factor` times this framerate
+    pub fn upsample<DFR: Framerate + ConstFramerate>(&self, factor: u32) -> Timecode<DFR> {
+        self.upsample_with_fr(&DFR::new(), factor)
+    }
+
+    ///Same as upsample, but with a dynamic framerate parameter
+    pub fn upsample_with_fr<DFR: Framerate>(&self, fr: &DFR, factor: u32) -> Timecode<DFR> {
+        assert_resample_factor(fr, self.framerate(), factor);
+
+        let count = self
+            .to_frame_count()
+            .checked_mul(factor)
+            .expect("Too large");
+        Timecode::from_frames(&Frames(count), fr)
+    }
+
+    ///Move to a framerate that is exactly `1 / factor` times this one, such as 50 to 25. Also
+    ///returns the number of frames (less than `factor`) that were dropped to get there.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<NDF<50>> = "01:00:00:25".parse().unwrap();
+    ///let (down, lost) = tc.downsample::<NDF<25>>(2);
+    ///assert_eq!(down.to_string(), "01:00:00:12");
+    ///assert_eq!(lost, 1);
+    ///```
+    ///
+    ///PANIC: if this framerate is not `factor` times the destination framerate
+    pub fn downsample<DFR: Framerate + ConstFramerate>(
+        &self,
+        factor: u32,
+    ) -> (Timecode<DFR>, FrameCount) {
+        self.downsample_with_fr(&DFR::new(), factor)
+    }
+
+    ///Same as downsample, but with a dynamic framerate parameter
+    pub fn downsample_with_fr<DFR: Framerate>(
+        &self,
+        fr: &DFR,
+        factor: u32,
+    ) -> (Timecode<DFR>, FrameCount) {
+        assert_resample_factor(self.framerate(), fr, factor);
+
+        let (count, lost) = div_rem(self.to_frame_count(), factor);
+        (Timecode::from_frames(&Frames(count), fr), lost)
+    }
+}
+
+impl Timecode<NDF<24>> {
+    ///Map a film frame to the first video frame it appears in after 2:3 pulldown. Every group of
+    ///4 film frames (A, B, C, D) is spread over 10 fields, which is 5 video frames:
+    ///
+    ///```text
+    ///video frame:  0     1     2     3     4
+    ///fields:       A A | B B | B C | C D | D D
+    ///```
+    ///
+    ///So A starts in video frame 0, B in 1, C in 2, and D in 3. Video frame 4 only repeats D.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let film: Timecode<NDF<24>> = "01:00:00:00".parse().unwrap();
+    ///assert_eq!(film.pulldown_2_3().to_string(), "01:00:03;18");
+    ///```
+    pub fn pulldown_2_3(&self) -> Timecode<DF<30>> {
+        const FIRST_VIDEO_FRAME: [FrameCount; 4] = [0, 1, 2, 3];
+
+        let (group, index) = div_rem(self.to_frame_count(), 4);
+        let video = group * 5 + FIRST_VIDEO_FRAME[index as usize];
+
+        Timecode::from_frames(&Frames(video), &DF::<30>)
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
     }
+    a
+}
+
+///PANIC: unless `high` is exactly `factor` times `low`
+fn assert_resample_factor(high: &impl Framerate, low: &impl Framerate, factor: u32) {
+    assert!(factor > 0, "resample factor must be positive");
+    assert!(
+        high.fr_num() * low.fr_denom() == factor as u64 * low.fr_num() * high.fr_denom(),
+        "framerates are not a factor of {} apart",
+        factor
+    );
 }
 
 /*
@@ -317,37 +996,24 @@ fn div_rem(a: FrameCount, b: FrameCount) -> (FrameCount, FrameCount) {
 }
 
 fn adjust_frame_count(drop_frames: u32, frame_count: u32) -> u32 {
+    //drop_frames is always framerate / 15, so a minute with dropped frames is 60 * 15 - 1 = 899
+    //times drop_frames long. The C version divides frames_per_10_mins by 10 instead, which is
+    //only exact up to 120fps.
     let frames_per_10_mins = drop_frames * (17982 / 2);
+    let frames_per_min = drop_frames * 899;
     let (d, mut m) = div_rem(frame_count, frames_per_10_mins);
 
     if m < drop_frames {
         m += drop_frames;
     }
 
-    frame_count
-        + 9 * drop_frames * d
-        + drop_frames * ((m - drop_frames) / (frames_per_10_mins / 10))
+    frame_count + 9 * drop_frames * d + drop_frames * ((m - drop_frames) / frames_per_min)
 }
 
 impl<FR: Framerate> ToFrames<FR> for Timecode<FR> {
     //This should be inlined after monomorphization so we shouldn't need inline
     fn to_frame_count(&self) -> FrameCount {
-        let max_frame = self.framerate().max_frame() as FrameCount;
-        let mut frame_count: FrameCount = 0;
-        frame_count += self.h as FrameCount * 60 * 60 * max_frame;
-        frame_count += self.m as FrameCount * 60 * max_frame;
-        frame_count += self.s as FrameCount * max_frame;
-        frame_count += self.f as FrameCount;
-
-        if let Some(drop_frames) = self.framerate().drop_frames() {
-            let minute_count = self.h as FrameCount * 60 + self.m as FrameCount;
-            //every 10 minutes, we /dont/ skip a frame. so count the number of times
-            //that happens. This should always be <= minute_count or we will panic.
-            let dropskip_count = minute_count / 10;
-            frame_count -= (minute_count - dropskip_count) * drop_frames;
-        }
-
-        frame_count
+        self.framerate().frames_for(self.h, self.m, self.s, self.f)
     }
 
     fn from_frames(&Frames(mut frame_count): &Frames, fr: &FR) -> Self {
@@ -365,14 +1031,255 @@ impl<FR: Framerate> ToFrames<FR> for Timecode<FR> {
         frame_count /= 60;
         let h = frame_count as u8;
 
-        Timecode {
-            f,
-            s,
-            m,
-            h,
-            framerate: *fr,
+        Timecode::from_parts(h, m, s, f, *fr)
+    }
+}
+
+///Number of frames in 24 hours of timecode at `fr`, taking dropped frames into account.
+///
+///```
+///# use timecode::{framerates::*, frames_per_day};
+///assert_eq!(frames_per_day(&NDF::<30>), 24 * 60 * 60 * 30);
+///assert_eq!(frames_per_day(&DF::<30>), 2589408);
+///```
+pub fn frames_per_day<FR: Framerate>(fr: &FR) -> FrameCount {
+    fr.frames_for(24, 0, 0, 0)
+}
+
+impl<FR: Framerate> Timecode<FR> {
+    ///Time remaining until midnight (`24:00:00:00`).
+    ///
+    ///At `00:00:00:00` this is the full day, `24:00:00:00`, rather than one frame less. Timecodes
+    ///at or beyond 24 hours return `00:00:00:00`.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<NDF<30>> = "23:59:59:00".parse().unwrap();
+    ///assert_eq!(tc.until_midnight().to_string(), "00:00:01:00");
+    ///```
+    pub fn until_midnight(&self) -> Timecode<FR> {
+        let remaining = frames_per_day(self.framerate()).saturating_sub(self.to_frame_count());
+        Timecode::from_frames(&Frames(remaining), self.framerate())
+    }
+
+    ///Frame count of the last frame before `24:00:00:00` at `fr`, i.e. [`frames_per_day`] minus
+    ///one.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, ToFrames, Frames};
+    ///let max = Timecode::max_frame_count_24h(&NDF::<30>);
+    ///assert_eq!(Timecode::from_frames(&Frames(max), &NDF::<30>).to_string(), "23:59:59:29");
+    ///```
+    pub fn max_frame_count_24h(fr: &FR) -> FrameCount {
+        frames_per_day(fr) - 1
+    }
+
+    ///Frame count of `255:59:59:<max_frame - 1>` at `fr`, the last timecode that fits in the
+    ///hours field. Larger counts wrap the hours in [`Timecode::from_frames`].
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, ToFrames, Frames};
+    ///let max = Timecode::max_frame_count(&NDF::<25>);
+    ///assert_eq!(max, 256 * 60 * 60 * 25 - 1);
+    ///```
+    pub fn max_frame_count(fr: &FR) -> FrameCount {
+        fr.frames_for(255, 59, 59, fr.max_frame() - 1)
+    }
+
+    ///Add frames, wrapping around at `24:00:00:00` back to `00:00:00:00`.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, Frames};
+    ///let tc: Timecode<DF<30>> = "23:59:59;29".parse().unwrap();
+    ///assert_eq!(tc.add_wrapping(Frames(2)).to_string(), "00:00:00;01");
+    ///```
+    pub fn add_wrapping(self, rhs: Frames) -> Timecode<FR> {
+        let day = frames_per_day(self.framerate()) as u64;
+        let total = (self.to_frame_count() as u64 + rhs.0 as u64) % day;
+        Timecode::from_frames(&Frames(total as FrameCount), self.framerate())
+    }
+
+    ///Subtract frames, wrapping around below `00:00:00:00` to the end of the previous day. See
+    ///[`Timecode::add_wrapping`].
+    pub fn sub_wrapping(self, rhs: Frames) -> Timecode<FR> {
+        let day = frames_per_day(self.framerate()) as u64;
+        let current = self.to_frame_count() as u64 % day;
+        let total = (current + day - rhs.0 as u64 % day) % day;
+        Timecode::from_frames(&Frames(total as FrameCount), self.framerate())
+    }
+
+    ///The start of the current second. For dropframe timecodes at the start of a minute that
+    ///skips frames, this is the first legal frame (`;02` at 29.97) rather than `;00`.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<DF<30>> = "00:01:00;15".parse().unwrap();
+    ///assert_eq!(tc.snap_prev_second_boundary().to_string(), "00:01:00;02");
+    ///```
+    pub fn snap_prev_second_boundary(&self) -> Timecode<FR> {
+        let first = self.first_legal_frame(self.m, self.s);
+        Timecode::from_parts(self.h, self.m, self.s, first, *self.framerate())
+    }
+
+    ///The lowest frame number that exists at minute `m`, second `s`
+    fn first_legal_frame(&self, m: u8, s: u8) -> FrameCount {
+        match self.framerate().drop_frames() {
+            Some(drop_frames) if s == 0 && !m.is_multiple_of(10) => drop_frames,
+            _ => 0,
         }
     }
+
+    ///Number of real frames since the start of the current minute. For dropframe timecodes, the
+    ///skipped frame numbers are not counted. A dropped address that was never validated, like
+    ///`00:01:00;00`, is before the first real frame of its minute and gives 0.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<DF<30>> = "00:01:01;00".parse().unwrap();
+    ///assert_eq!(tc.frames_into_minute(), 28);
+    ///```
+    pub fn frames_into_minute(&self) -> FrameCount {
+        let first = self.first_legal_frame(self.m, 0);
+        self.to_frame_count()
+            .saturating_sub(self.framerate().frames_for(self.h, self.m, 0, first))
+    }
+
+    ///Number of real frames since the start of the current hour. See
+    ///[`Timecode::frames_into_minute`].
+    pub fn frames_into_hour(&self) -> FrameCount {
+        self.to_frame_count()
+            .saturating_sub(self.framerate().frames_for(self.h, 0, 0, 0))
+    }
+
+    ///The start of the next second, skipping over dropped frame numbers in the same way as
+    ///[`Timecode::snap_prev_second_boundary`].
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<DF<30>> = "00:00:59;15".parse().unwrap();
+    ///assert_eq!(tc.snap_next_second_boundary().to_string(), "00:01:00;02");
+    ///```
+    pub fn snap_next_second_boundary(&self) -> Timecode<FR> {
+        let prev = self.snap_prev_second_boundary();
+        prev + Frames(self.framerate().max_frame() - prev.f)
+    }
+
+    ///The last legal timecode at or before `frame`.
+    ///
+    ///Frame counts only ever refer to real frames, so this is always the timecode of `frame`
+    ///itself, the same as [`ToFrames::from_frames`]. Dropframe addresses that do not exist are
+    ///never produced.
+    pub fn legal_at_or_before(frame: FrameCount, fr: &FR) -> Timecode<FR> {
+        Timecode::from_frames(&Frames(frame), fr)
+    }
+
+    ///The first legal timecode at or after `frame`. See [`Timecode::legal_at_or_before`].
+    pub fn legal_at_or_after(frame: FrameCount, fr: &FR) -> Timecode<FR> {
+        Timecode::from_frames(&Frames(frame), fr)
+    }
+
+    ///Parse a plain frame count, such as `"1860"`, into a timecode.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc = Timecode::from_frame_str("1860", &NDF::<30>).unwrap();
+    ///assert_eq!(tc.to_string(), "00:01:02:00");
+    ///```
+    pub fn from_frame_str(s: &str, fr: &FR) -> Result<Timecode<FR>, TimecodeValidationError> {
+        let frames: Frames = s.parse().map_err(|_| TimecodeValidationError::Unparsed)?;
+        Ok(Timecode::from_frames(&frames, fr))
+    }
+
+    ///The timecode of the nearest whole frame to `frame`. Negative positions become
+    ///`00:00:00:00`.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, ToFrames};
+    ///let tc = Timecode::from_frame_f64(1234.7, &NDF::<25>);
+    ///assert_eq!(tc.to_frame_count(), 1235);
+    ///```
+    pub fn from_frame_f64(frame: f64, fr: &FR) -> Timecode<FR> {
+        //float to int casts saturate, so negatives and NaN become 0
+        Timecode::from_frames(&Frames(frame.round() as FrameCount), fr)
+    }
+
+    ///The timecode of the frame containing byte `offset` of a constant bitrate stream, where
+    ///every frame is `bytes_per_frame` bytes long.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc = Timecode::from_byte_offset(25 * 1000 + 999, 1000, &NDF::<25>);
+    ///assert_eq!(tc.to_string(), "00:00:01:00");
+    ///assert_eq!(tc.to_byte_offset(1000), 25 * 1000);
+    ///```
+    ///
+    ///PANIC: if `bytes_per_frame` is zero, or the frame count does not fit in a [`FrameCount`]
+    pub fn from_byte_offset(offset: u64, bytes_per_frame: u64, fr: &FR) -> Timecode<FR> {
+        let frame = (offset / bytes_per_frame).try_into().expect("Too large");
+        Timecode::from_frames(&Frames(frame), fr)
+    }
+
+    ///Byte offset of the start of this frame in a constant bitrate stream. See
+    ///[`Timecode::from_byte_offset`].
+    pub fn to_byte_offset(&self, bytes_per_frame: u64) -> u64 {
+        self.to_frame_count() as u64 * bytes_per_frame
+    }
+
+    ///Build a timecode from a whole number of seconds since `00:00:00:00` and a frame within
+    ///that second. The result is validated like a parsed timecode, so dropped frame numbers are
+    ///rejected.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc = Timecode::from_seconds_and_frame(3661, 12, &NDF::<25>).unwrap();
+    ///assert_eq!(tc.to_string(), "01:01:01:12");
+    ///
+    ///assert!(Timecode::from_seconds_and_frame(60, 0, &DF::<30>).is_err());
+    ///```
+    pub fn from_seconds_and_frame(
+        secs: u32,
+        frame: FrameCount,
+        fr: &FR,
+    ) -> Result<Timecode<FR>, TimecodeValidationError> {
+        let h = (secs / 3600)
+            .try_into()
+            .map_err(|_| TimecodeValidationError::Unparsed)?;
+
+        Timecode::from_hmsf_with_fr(h, (secs / 60 % 60) as u8, (secs % 60) as u8, frame, fr)
+    }
+}
+
+impl Frames {
+    ///Scale a frame count measured at `src` to the same real time at `dst`, rounding down. This
+    ///is the frame count equivalent of [`Convert::convert_with_fr`].
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Frames};
+    ///assert_eq!(Frames(1800).convert(&NDF::<25>, &NDF::<30>), Frames(2160));
+    ///```
+    ///
+    ///PANIC: if the result does not fit in a [`FrameCount`]
+    pub fn convert(&self, src: &impl Framerate, dst: &impl Framerate) -> Frames {
+        let count = self.0 as u128 * dst.fr_num() as u128 * src.fr_denom() as u128;
+        let count = count / dst.fr_denom() as u128 / src.fr_num() as u128;
+
+        Frames(count.try_into().expect("Too large"))
+    }
+}
+
+///Parses a plain frame count, such as `"1860"`
+impl FromStr for Frames {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Frames)
+    }
+}
+
+impl Display for Frames {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
 
 impl ToFrames<()> for Frames {
@@ -393,10 +1300,104 @@ impl<FR: Framerate> std::ops::Add<Timecode<FR>> for Timecode<FR> {
     }
 }
 
+///The number of frames between two timecodes
+///
+///```
+///# use timecode::{framerates::*, Timecode, Frames};
+///let a: Timecode<DF<30>> = "00:01:00;02".parse().unwrap();
+///let b: Timecode<DF<30>> = "00:00:59;29".parse().unwrap();
+///assert_eq!(a - b, Frames(1));
+///```
+impl<FR: Framerate> std::ops::Sub<Timecode<FR>> for Timecode<FR> {
+    type Output = Frames;
+
+    ///PANIC: if rhs > lhs, or the framerates differ
+    fn sub(self, rhs: Timecode<FR>) -> Self::Output {
+        self.try_sub(rhs).expect("Failed to subtract")
+    }
+}
+
 ///Error returned when adding two incompatable [`DynFramerate`] timecodes
 #[derive(Debug)]
 pub struct FramerateMismatch;
 
+///How [`Convert::convert_with_fr_rounded`] turns a fractional frame count into a whole frame
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum RoundMode {
+    ///Round down, the same as [`Convert::convert_with_fr`]
+    Floor,
+    ///Round up
+    Ceil,
+    ///Round to the nearest frame. Exact halves round to the even frame, which matches the
+    ///reference conversions in `tests/samples`.
+    Nearest,
+}
+
+///Error returned by [`Convert::try_convert_with_fr`]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ConversionError {
+    ///The converted frame count does not fit in a [`FrameCount`]
+    TooLarge,
+}
+
+///Error returned when subtracting more frames than a timecode contains
+#[derive(Debug)]
+pub struct Underflow;
+
+///Error returned when a frame count would no longer fit in a [`FrameCount`], or a timecode would
+///be past `255` hours
+#[derive(Debug)]
+pub struct Overflow;
+
+///Error returned by [`Timecode::check_broadcast_start`]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum BroadcastError {
+    ///The program does not start at `00:00:00:00` or `01:00:00:00`
+    NotHourStart,
+    ///The program is NTSC, but not dropframe
+    NotDropFrame,
+}
+
+impl std::fmt::Display for BroadcastError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BroadcastError::NotHourStart => write!(f, "Program must start at 00 or 01 hours"),
+            BroadcastError::NotDropFrame => write!(f, "NTSC program must be dropframe"),
+        }
+    }
+}
+
+impl std::error::Error for BroadcastError {}
+
+impl<FR: Framerate> Timecode<FR> {
+    ///Check that this is an acceptable first frame for a broadcast delivery: exactly
+    ///`00:00:00:00` or `01:00:00:00`, and dropframe if the program is NTSC.
+    ///
+    ///There is no 29.97 non-drop framerate in this crate, so a timecode counting 30 or 60 frames
+    ///per second is assumed to be NTSC.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, BroadcastError};
+    ///let tc: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+    ///assert_eq!(tc.check_broadcast_start(), Ok(()));
+    ///
+    ///let tc: Timecode<NDF<30>> = "01:00:00:00".parse().unwrap();
+    ///assert_eq!(tc.check_broadcast_start(), Err(BroadcastError::NotDropFrame));
+    ///```
+    pub fn check_broadcast_start(&self) -> Result<(), BroadcastError> {
+        if self.h > 1 || self.m != 0 || self.s != 0 || self.f != 0 {
+            return Err(BroadcastError::NotHourStart);
+        }
+
+        let fr = self.framerate();
+        if fr.max_frame().is_multiple_of(30) && !fr.is_dropframe() {
+            return Err(BroadcastError::NotDropFrame);
+        }
+
+        Ok(())
+    }
+}
+
 impl<FR: Framerate> Timecode<FR> {
     fn try_add(self, rhs: Timecode<FR>) -> Result<Self, FramerateMismatch> {
         if self.framerate() != rhs.framerate() {
@@ -405,24 +1406,196 @@ impl<FR: Framerate> Timecode<FR> {
         let frames = Frames(self.to_frame_count()) + Frames(rhs.to_frame_count());
         Ok(Timecode::from_frames(&frames, self.framerate()))
     }
-}
-
-impl<FR: Framerate> std::ops::Add<Frames> for Timecode<FR> {
-    type Output = Self;
 
-    fn add(self, rhs: Frames) -> Self::Output {
-        let frames = Frames(self.to_frame_count()) + rhs;
-        Timecode::from_frames(&frames, self.framerate())
+    ///Number of frames from `rhs` to `self`, failing if the framerates differ. This can only
+    ///happen with [`DynFramerate`].
+    ///
+    ///PANIC: if rhs > lhs
+    pub fn try_sub(self, rhs: Timecode<FR>) -> Result<Frames, FramerateMismatch> {
+        if self.framerate() != rhs.framerate() {
+            return Err(FramerateMismatch);
+        }
+        Ok(Frames(self.to_frame_count()) - Frames(rhs.to_frame_count()))
     }
-}
 
-impl std::ops::Add<Frames> for Frames {
-    type Output = Frames;
+    ///Advance this timecode by `rhs` frames, failing instead of panicking if the frame count
+    ///overflows or the result would be past [`Timecode::max_frame_count`].
+    pub fn add_frames(self, Frames(rhs): Frames) -> Result<Self, Overflow> {
+        let count = self
+            .to_frame_count()
+            .checked_add(rhs)
+            .filter(|&count| count <= Self::max_frame_count(self.framerate()))
+            .ok_or(Overflow)?;
+        Ok(Timecode::from_frames(&Frames(count), self.framerate()))
+    }
 
-    fn add(self, rhs: Frames) -> Self::Output {
-        Self(self.0 + rhs.0)
+    ///Move this timecode back by `rhs` frames, failing instead of panicking if it would go below
+    ///`00:00:00:00`.
+    pub fn sub_frames(self, Frames(rhs): Frames) -> Result<Self, Underflow> {
+        let count = self.to_frame_count().checked_sub(rhs).ok_or(Underflow)?;
+        Ok(Timecode::from_frames(&Frames(count), self.framerate()))
     }
-}
+
+    ///Advance this timecode by `rhs` frames, or `None` if the result would be past the last
+    ///frame of the day. See [`Timecode::max_frame_count_24h`].
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, Frames};
+    ///let tc: Timecode<NDF<25>> = "23:59:59:24".parse().unwrap();
+    ///assert!(tc.checked_add(Frames(0)).is_some());
+    ///assert!(tc.checked_add(Frames(1)).is_none());
+    ///```
+    pub fn checked_add(self, Frames(rhs): Frames) -> Option<Self> {
+        let count = self.to_frame_count().checked_add(rhs)?;
+        if count > Self::max_frame_count_24h(self.framerate()) {
+            return None;
+        }
+        Some(Timecode::from_frames(&Frames(count), self.framerate()))
+    }
+
+    ///Move this timecode back by `rhs` frames, or `None` if it would go below `00:00:00:00` or
+    ///started past the last frame of the day.
+    pub fn checked_sub(self, Frames(rhs): Frames) -> Option<Self> {
+        let count = self.to_frame_count().checked_sub(rhs)?;
+        if count > Self::max_frame_count_24h(self.framerate()) {
+            return None;
+        }
+        Some(Timecode::from_frames(&Frames(count), self.framerate()))
+    }
+
+    ///Add two timecodes, or `None` if the framerates differ or the result would be past the
+    ///last frame of the day.
+    pub fn checked_add_tc(self, rhs: Timecode<FR>) -> Option<Self> {
+        if self.framerate() != rhs.framerate() {
+            return None;
+        }
+        self.checked_add(Frames(rhs.to_frame_count()))
+    }
+
+    ///The timecode at the average frame count of `tcs`, rounded down, or `None` if `tcs` is
+    ///empty.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tcs: Vec<Timecode<NDF<25>>> = vec![
+    ///    "00:00:00:00".parse().unwrap(),
+    ///    "00:00:02:00".parse().unwrap(),
+    ///];
+    ///assert_eq!(Timecode::mean(&tcs).unwrap().to_string(), "00:00:01:00");
+    ///```
+    ///
+    ///PANIC: if the timecodes do not all have the same framerate
+    pub fn mean(tcs: &[Timecode<FR>]) -> Option<Timecode<FR>> {
+        let first = tcs.first()?;
+        assert!(
+            tcs.iter().all(|tc| tc.framerate() == first.framerate()),
+            "all timecodes must have the same framerate"
+        );
+
+        let sum: u64 = tcs.iter().map(|tc| tc.to_frame_count() as u64).sum();
+        let mean = sum / tcs.len() as u64;
+
+        //the mean is never larger than the largest input, so this always fits
+        Some(Timecode::from_frames(
+            &Frames(mean as FrameCount),
+            first.framerate(),
+        ))
+    }
+
+    ///How far through `total` this timecode is, from `0.0` to `1.0`. A zero length `total` is
+    ///always complete.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let total: Timecode<NDF<25>> = "00:01:00:00".parse().unwrap();
+    ///let tc: Timecode<NDF<25>> = "00:00:30:00".parse().unwrap();
+    ///assert_eq!(tc.fraction_elapsed(&total), 0.5);
+    ///```
+    ///
+    ///PANIC: if the framerates differ
+    pub fn fraction_elapsed(&self, total: &Timecode<FR>) -> f64 {
+        assert!(
+            self.framerate() == total.framerate(),
+            "timecodes must have the same framerate"
+        );
+
+        let total = total.to_frame_count();
+        if total == 0 {
+            return 1.0;
+        }
+        (self.to_frame_count() as f64 / total as f64).clamp(0.0, 1.0)
+    }
+
+    ///Move this timecode back by `rhs` frames, stopping at `00:00:00:00` instead of failing.
+    pub fn saturating_sub_frames(self, Frames(rhs): Frames) -> Self {
+        let count = self.to_frame_count().saturating_sub(rhs);
+        Timecode::from_frames(&Frames(count), self.framerate())
+    }
+
+    ///The start of the GOP containing this timecode, for GOPs of `gop` frames counted from
+    ///`00:00:00:00`. This floors the frame count to a multiple of `gop`.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let tc: Timecode<NDF<30>> = "00:00:01:10".parse().unwrap();
+    ///assert_eq!(tc.gop_align(15).to_string(), "00:00:01:00");
+    ///assert_eq!(tc.next_gop(15).to_string(), "00:00:01:15");
+    ///```
+    ///
+    ///PANIC: if `gop` is zero
+    pub fn gop_align(&self, gop: FrameCount) -> Timecode<FR> {
+        assert!(gop > 0, "GOP size must be at least one frame");
+
+        let count = self.to_frame_count();
+        Timecode::from_frames(&Frames(count - count % gop), self.framerate())
+    }
+
+    ///The start of the GOP after the one containing this timecode. See [`Timecode::gop_align`].
+    ///
+    ///PANIC: if `gop` is zero
+    pub fn next_gop(&self, gop: FrameCount) -> Timecode<FR> {
+        self.gop_align(gop) + Frames(gop)
+    }
+
+    ///The frame count this timecode would have if no frame numbers were ever dropped. For
+    ///non-drop framerates this is the same as [`ToFrames::to_frame_count`].
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, ToFrames};
+    ///let tc: Timecode<DF<30>> = "00:01:00;02".parse().unwrap();
+    ///assert_eq!(tc.to_frame_count(), 1800);
+    ///assert_eq!(tc.display_frame_count(), 1802);
+    ///```
+    pub fn display_frame_count(&self) -> FrameCount {
+        let max_frame = self.framerate().max_frame();
+        ((self.h as FrameCount * 60 + self.m as FrameCount) * 60 + self.s as FrameCount) * max_frame
+            + self.f
+    }
+
+    ///Difference between the displayed frame numbers of `self` and `other`, as
+    ///`self - other`. For dropframe timecodes this includes the frame numbers that were skipped,
+    ///so it can be larger than the real number of frames between them.
+    pub fn display_frame_diff(&self, other: &Timecode<FR>) -> i64 {
+        self.display_frame_count() as i64 - other.display_frame_count() as i64
+    }
+}
+
+impl<FR: Framerate> std::ops::Add<Frames> for Timecode<FR> {
+    type Output = Self;
+
+    fn add(self, rhs: Frames) -> Self::Output {
+        let frames = Frames(self.to_frame_count()) + rhs;
+        Timecode::from_frames(&frames, self.framerate())
+    }
+}
+
+impl std::ops::Add<Frames> for Frames {
+    type Output = Frames;
+
+    fn add(self, rhs: Frames) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
 
 impl<FR: Framerate> std::ops::Sub<Frames> for Timecode<FR> {
     type Output = Self;
@@ -446,6 +1619,141 @@ impl std::ops::Sub<Frames> for Frames {
     }
 }
 
+impl<FR: Framerate> std::ops::AddAssign<Frames> for Timecode<FR> {
+    fn add_assign(&mut self, rhs: Frames) {
+        *self = *self + rhs;
+    }
+}
+
+impl<FR: Framerate> std::ops::SubAssign<Frames> for Timecode<FR> {
+    ///PANIC: if rhs > lhs
+    fn sub_assign(&mut self, rhs: Frames) {
+        *self = *self - rhs;
+    }
+}
+
+impl std::ops::AddAssign<Frames> for Frames {
+    fn add_assign(&mut self, rhs: Frames) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::SubAssign<Frames> for Frames {
+    ///PANIC: if rhs > lhs
+    fn sub_assign(&mut self, rhs: Frames) {
+        *self = *self - rhs;
+    }
+}
+
+///Compares the frame count of the timecode with a number of frames.
+///
+///```
+///# use timecode::{framerates::*, Timecode, Frames};
+///let tc: Timecode<NDF<30>> = "00:01:00:00".parse().unwrap();
+///assert!(tc >= Frames(1800));
+///assert!(tc == Frames(1800));
+///```
+impl<FR: Framerate> PartialEq<Frames> for Timecode<FR> {
+    fn eq(&self, other: &Frames) -> bool {
+        self.to_frame_count() == other.0
+    }
+}
+
+impl<FR: Framerate> PartialOrd<Frames> for Timecode<FR> {
+    fn partial_cmp(&self, other: &Frames) -> Option<std::cmp::Ordering> {
+        Some(self.to_frame_count().cmp(&other.0))
+    }
+}
+
+///Timecodes are ordered by the real time they happen at, so a [`DynFramerate`] timecode at
+///29.97fps can be compared with one at 25fps. Timecodes at the same instant but with different
+///framerates are then ordered by framerate.
+///
+///```
+///# use timecode::{framerates::*, Timecode};
+///let mut tcs: Vec<Timecode<NDF<30>>> = ["01:00:00:00", "00:59:59:29"]
+///    .iter()
+///    .map(|s| s.parse().unwrap())
+///    .collect();
+///tcs.sort();
+///assert_eq!(tcs[0].to_string(), "00:59:59:29");
+///```
+impl<FR: Framerate + Eq> Ord for Timecode<FR> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.cmp_realtime(other)
+            .then_with(|| {
+                let (fr, other_fr) = (self.framerate(), other.framerate());
+                (fr.fr_num(), fr.fr_denom(), fr.is_dropframe()).cmp(&(
+                    other_fr.fr_num(),
+                    other_fr.fr_denom(),
+                    other_fr.is_dropframe(),
+                ))
+            })
+            .then_with(|| {
+                (self.h, self.m, self.s, self.f).cmp(&(other.h, other.m, other.s, other.f))
+            })
+    }
+}
+
+impl<FR: Framerate + Eq> PartialOrd for Timecode<FR> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<FR: Framerate> Timecode<FR> {
+    ///True if `self` and `other` are no more than `frames` frames apart in real time, measured in
+    ///frames of whichever framerate is slower. Useful for checking conversions, which can be
+    ///off by one frame.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, Convert};
+    ///let tc: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+    ///let converted: Timecode<NDF<25>> = tc.convert();
+    ///assert!(converted.close_to(&tc, 1));
+    ///```
+    pub fn close_to<FR2: Framerate>(&self, other: &Timecode<FR2>, frames: FrameCount) -> bool {
+        let (fr, other_fr) = (self.framerate(), other.framerate());
+        let (n1, d1) = (fr.fr_num() as u128, fr.fr_denom() as u128);
+        let (n2, d2) = (other_fr.fr_num() as u128, other_fr.fr_denom() as u128);
+
+        //both times are over a denominator of n1 * n2
+        let a = self.to_frame_count() as u128 * d1 * n2;
+        let b = other.to_frame_count() as u128 * d2 * n1;
+
+        let (nc, dc) = if n1 * d2 < n2 * d1 {
+            (n1, d1)
+        } else {
+            (n2, d2)
+        };
+
+        a.abs_diff(b) * nc <= frames as u128 * dc * n1 * n2
+    }
+
+    ///Compare the real time of two timecodes, which may have different framerates. The comparison
+    ///is exact: each frame count is scaled by its framerate ratio using integer math.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///# use std::cmp::Ordering;
+    ///let ndf: Timecode<NDF<30>> = "01:00:00:00".parse().unwrap();
+    ///let df: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+    ///
+    /////dropframe timecode is not exact, 01:00:00;00 is a few milliseconds before one real hour
+    ///assert_eq!(ndf.cmp_realtime(&df), Ordering::Greater);
+    ///assert_eq!(df.cmp_realtime(&df), Ordering::Equal);
+    ///```
+    pub fn cmp_realtime<FR2: Framerate>(&self, other: &Timecode<FR2>) -> std::cmp::Ordering {
+        //count / (num / denom) = seconds, so cross multiply to compare exactly
+        let (fr, other_fr) = (self.framerate(), other.framerate());
+        let lhs = self.to_frame_count() as u128 * fr.fr_denom() as u128 * other_fr.fr_num() as u128;
+        let rhs =
+            other.to_frame_count() as u128 * other_fr.fr_denom() as u128 * fr.fr_num() as u128;
+
+        lhs.cmp(&rhs)
+    }
+}
+
 impl<FR1> PartialEq<Timecode<FR1>> for Timecode<DynFramerate>
 where
     FR1: Framerate + ConstFramerate,
@@ -459,10 +1767,207 @@ where
     }
 }
 
+impl<FR: Framerate> Timecode<FR> {
+    ///Loose equality for timecodes that may not share a framerate type, such as a `DF<30>`
+    ///against a [`DynFramerate`]. The fields must match and the framerates must run at the same
+    ///speed, but the separator each was written with is ignored.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, parser::Seperator, Timecode};
+    ///let a: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+    ///let b: Timecode<DynFramerate> = "01:00:00:00@29.97".parse().unwrap();
+    ///assert!(a.eq_ignoring_separator(&b.with_separator(Some(Seperator::Colon))));
+    ///```
+    pub fn eq_ignoring_separator<FR2: Framerate>(&self, other: &Timecode<FR2>) -> bool {
+        let (fr, other_fr) = (self.framerate(), other.framerate());
+        let same_ratio = fr.fr_num() as u128 * other_fr.fr_denom() as u128
+            == other_fr.fr_num() as u128 * fr.fr_denom() as u128;
+
+        self.h == other.h
+            && self.m == other.m
+            && self.s == other.s
+            && self.f == other.f
+            && same_ratio
+    }
+}
+
 #[cfg(test)]
 mod add_test {
     use super::*;
 
+    #[test]
+    fn from_hmsf() {
+        let tc = Timecode::<NDF<25>>::from_hmsf(10, 0, 0, 24).unwrap();
+        assert_eq!(tc, "10:00:00:24".parse::<Timecode<NDF<25>>>().unwrap());
+
+        assert_eq!(
+            Timecode::<NDF<25>>::from_hmsf(0, 0, 0, 25),
+            Err(TimecodeValidationError::InvalidFrames(25))
+        );
+        assert_eq!(
+            Timecode::<NDF<25>>::from_hmsf(0, 60, 0, 0),
+            Err(TimecodeValidationError::InvalidMin(60))
+        );
+
+        let fr = DynFramerate::new_df(60);
+        let tc = Timecode::from_hmsf_with_fr(0, 1, 0, 4, &fr).unwrap();
+        assert_eq!(tc.to_string(), "00:01:00;04");
+        assert!(Timecode::from_hmsf_with_fr(0, 1, 0, 1, &fr).is_err());
+    }
+
+    #[test]
+    fn zero() {
+        let mut total = Timecode::<DF<30>>::zero();
+        assert_eq!(total.to_frame_count(), 0);
+        for _ in 0..3 {
+            total += Frames(20);
+        }
+        assert_eq!(total.to_string(), "00:00:02;00");
+
+        let fr = DynFramerate::new_ndf(25);
+        assert_eq!(Timecode::zero_with_fr(&fr).to_frame_count(), 0);
+        assert_eq!(Timecode::zero_with_fr(&fr), Timecode::<NDF<25>>::default());
+    }
+
+    #[test]
+    fn add_sub_assign() {
+        let mut tc: Timecode<DF<30>> = "00:00:59;28".parse().unwrap();
+        tc += Frames(1);
+        tc += Frames(1);
+        assert_eq!(tc.to_string(), "00:01:00;02");
+        tc -= Frames(2);
+        assert_eq!(tc.to_string(), "00:00:59;28");
+
+        let mut frames = Frames(10);
+        frames += Frames(5);
+        frames -= Frames(15);
+        assert_eq!(frames, Frames(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn sub_assign_underflow() {
+        let mut tc: Timecode<NDF<25>> = "00:00:00:00".parse().unwrap();
+        tc -= Frames(1);
+    }
+
+    #[test]
+    fn checked_add_sub() {
+        let tc: Timecode<DF<30>> = "23:59:59;29".parse().unwrap();
+        assert_eq!(tc.checked_add(Frames(0)), Some(tc));
+        assert_eq!(tc.checked_add(Frames(1)), None);
+        assert_eq!(tc.checked_add(Frames(FrameCount::MAX)), None);
+        assert_eq!(
+            tc.checked_sub(Frames(1)).unwrap().to_string(),
+            "23:59:59;28"
+        );
+
+        let zero: Timecode<DF<30>> = "00:00:00;00".parse().unwrap();
+        assert_eq!(zero.checked_sub(Frames(0)), Some(zero));
+        assert_eq!(zero.checked_sub(Frames(1)), None);
+
+        let late: Timecode<DF<30>> = "30:00:00;00".parse().unwrap();
+        assert_eq!(late.checked_sub(Frames(1)), None);
+    }
+
+    #[test]
+    fn checked_add_tc() {
+        let a = Timecode::new_with_fr("00:00:59;29", "29.97").unwrap();
+        let b = Timecode::new_with_fr("00:00:00;01", "29.97").unwrap();
+        let c = Timecode::new_with_fr("00:00:00:01", "30").unwrap();
+
+        assert_eq!(a.checked_add_tc(b).unwrap().to_string(), "00:01:00;02");
+        assert_eq!(a.checked_add_tc(c), None);
+
+        let end = Timecode::new_with_fr("23:59:59;29", "29.97").unwrap();
+        assert_eq!(end.checked_add_tc(b), None);
+    }
+
+    #[test]
+    fn sub_timecodes() {
+        let a: Timecode<NDF<30>> = "01:00:00:00".parse().unwrap();
+        let b: Timecode<NDF<30>> = "00:59:59:29".parse().unwrap();
+        assert_eq!(a - b, Frames(1));
+        assert_eq!(a - a, Frames(0));
+
+        let a = Timecode::new_with_fr("00:10:00;00", "29.97").unwrap();
+        let b = Timecode::new_with_fr("00:00:00;00", "29.97").unwrap();
+        let c = Timecode::new_with_fr("00:00:00:00", "30").unwrap();
+        assert_eq!(a.try_sub(b).unwrap(), Frames(17982));
+        assert!(a.try_sub(c).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn sub_timecodes_underflow() {
+        let a: Timecode<NDF<30>> = "01:00:00:00".parse().unwrap();
+        let b: Timecode<NDF<30>> = "00:59:59:29".parse().unwrap();
+        let _ = b - a;
+    }
+
+    #[test]
+    fn fraction_elapsed() {
+        let total: Timecode<DF<30>> = "00:20:00;00".parse().unwrap();
+        let half: Timecode<DF<30>> = "00:10:00;00".parse().unwrap();
+        let zero: Timecode<DF<30>> = "00:00:00;00".parse().unwrap();
+        let past: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+
+        assert_eq!(half.fraction_elapsed(&total), 0.5);
+        assert_eq!(zero.fraction_elapsed(&total), 0.0);
+        assert_eq!(total.fraction_elapsed(&total), 1.0);
+        assert_eq!(past.fraction_elapsed(&total), 1.0);
+        assert_eq!(zero.fraction_elapsed(&zero), 1.0);
+    }
+
+    #[test]
+    fn mean() {
+        let tcs: Vec<Timecode<NDF<25>>> = ["00:00:01:00", "00:00:02:00", "00:00:03:10"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        assert_eq!(Timecode::mean(&tcs).unwrap().to_string(), "00:00:02:03");
+
+        assert_eq!(Timecode::<NDF<25>>::mean(&[]), None);
+
+        //both counts fit in a FrameCount, but their sum does not
+        let hours =
+            |h: FrameCount| Timecode::from_frames(&Frames(h * 60 * 60 * 4000), &NDF::<4000>);
+        let (late, early) = (hours(255), hours(254));
+        assert_eq!(late.h, 255);
+        assert!(
+            late.to_frame_count() as u64 + early.to_frame_count() as u64 > FrameCount::MAX as u64
+        );
+
+        let mean = Timecode::mean(&[late, early]).unwrap();
+        assert_eq!((mean.h, mean.m, mean.s, mean.f), (254, 30, 0, 0));
+        assert_eq!(mean.to_frame_count(), 254 * 60 * 60 * 4000 + 30 * 60 * 4000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mean_mismatched_framerates() {
+        let a = Timecode::new_with_fr("00:00:01:00", "25").unwrap();
+        let b = Timecode::new_with_fr("00:00:01:00", "30").unwrap();
+        let _ = Timecode::mean(&[a, b]);
+    }
+
+    #[test]
+    fn saturating_sub_frames() {
+        let tc: Timecode<DF<30>> = "00:01:00;02".parse().unwrap();
+        assert_eq!(
+            tc.saturating_sub_frames(Frames(1)).to_string(),
+            "00:00:59;29"
+        );
+        assert_eq!(
+            tc.saturating_sub_frames(Frames(1800)).to_string(),
+            "00:00:00;00"
+        );
+        assert_eq!(
+            tc.saturating_sub_frames(Frames(1801)).to_string(),
+            "00:00:00;00"
+        );
+    }
+
     #[test]
     fn add_compiles() {
         let t1: Timecode<NDF<30>> = "01:10:00:12".parse().unwrap();
@@ -493,6 +1998,34 @@ mod add_test {
         assert_eq!(f, 12 + 30);
     }
 
+    #[test]
+    #[allow(clippy::nonminimal_bool)]
+    fn compare_frames() {
+        let t1: Timecode<DF<30>> = "00:01:00;02".parse().unwrap();
+
+        assert!(t1 == Frames(1800));
+        assert!(t1 != Frames(1802));
+        assert!(t1 >= Frames(1800));
+        assert!(t1 > Frames(1799));
+        assert!(t1 < Frames(1801));
+        assert!(!(t1 < Frames(1800)));
+    }
+
+    #[test]
+    fn display_diff_across_df_minute() {
+        let t1: Timecode<DF<30>> = "00:00:59;29".parse().unwrap();
+        let t2: Timecode<DF<30>> = "00:01:00;02".parse().unwrap();
+
+        assert_eq!(t2.to_frame_count() - t1.to_frame_count(), 1);
+        assert_eq!(t2.display_frame_diff(&t1), 3);
+        assert_eq!(t1.display_frame_diff(&t2), -3);
+
+        let t1: Timecode<NDF<30>> = "00:00:59:29".parse().unwrap();
+        let t2: Timecode<NDF<30>> = "00:01:00:02".parse().unwrap();
+        assert_eq!(t2.display_frame_diff(&t1), 3);
+        assert_eq!(t2.display_frame_count(), t2.to_frame_count());
+    }
+
     #[test]
     fn add_tcs() {
         let t1: Timecode<NDF<30>> = "01:10:00:12".parse().unwrap();
@@ -502,6 +2035,47 @@ mod add_test {
         assert_eq!(t1 + t2, t3);
     }
 
+    #[test]
+    fn add_sub_frames_checked() {
+        let t1: Timecode<NDF<30>> = "00:00:01:00".parse().unwrap();
+
+        assert_eq!(t1.add_frames(Frames(5)).unwrap().to_string(), "00:00:01:05");
+        assert_eq!(
+            t1.sub_frames(Frames(30)).unwrap().to_string(),
+            "00:00:00:00"
+        );
+        assert!(t1.sub_frames(Frames(31)).is_err());
+        assert!(t1.add_frames(Frames(FrameCount::MAX)).is_err());
+    }
+
+    #[test]
+    fn add_frames_hour_limit() {
+        //far below FrameCount::MAX, but past 255:59:59:24
+        let t1: Timecode<NDF<25>> = "00:00:00:00".parse().unwrap();
+        let max = Timecode::max_frame_count(&NDF::<25>);
+
+        let last = t1.add_frames(Frames(max)).unwrap();
+        assert_eq!((last.h, last.m, last.s, last.f), (255, 59, 59, 24));
+        assert!(t1.add_frames(Frames(max + 1)).is_err());
+        assert!(last.add_frames(Frames(1)).is_err());
+        assert!(t1.add_frames(Frames(30_000_000)).is_err());
+
+        let t1: Timecode<DF<30>> = "255:59:59;28".parse().unwrap();
+        assert!(t1.add_frames(Frames(1)).is_ok());
+        assert!(t1.add_frames(Frames(2)).is_err());
+    }
+
+    #[test]
+    fn rebuild_from_count_and_tag() {
+        let t1: Timecode<DynFramerate> = "01:10:00;12@29.97".parse().unwrap();
+
+        let count = t1.to_frame_count();
+        let tag = t1.framerate().to_tag();
+
+        let fr: DynFramerate = tag.parse().unwrap();
+        assert_eq!(Timecode::from_frames(&Frames(count), &fr), t1);
+    }
+
     #[test]
     fn dyns() {
         let t1: Timecode<DynFramerate> = "01:10:00:12@30".parse().unwrap();
@@ -510,6 +2084,51 @@ mod add_test {
         let _ = t1 + t2;
     }
 
+    #[test]
+    fn dyns_parse_sep() {
+        let t1: Timecode<DynFramerate> = "01:10:00:12@30".parse().unwrap();
+        let t2: Timecode<DynFramerate> = "01:10:00:12 30".parse().unwrap();
+        let t3: Timecode<DynFramerate> = "01:10:00:12\t 30 ".parse().unwrap();
+
+        assert_eq!(t1, t2);
+        assert_eq!(t1, t3);
+        assert_eq!(t1.framerate(), &DynFramerate::new_ndf(30));
+
+        assert!("01:10:00:12".parse::<Timecode<DynFramerate>>().is_err());
+        assert!("01:10:00:12 clip 30"
+            .parse::<Timecode<DynFramerate>>()
+            .is_err());
+    }
+
+    #[test]
+    fn dyns_parse_detailed() {
+        let t1 = Timecode::parse_detailed("01:10:00:12@30").unwrap();
+        assert_eq!(t1.to_string(), "01:10:00:12");
+
+        assert_eq!(
+            Timecode::parse_detailed("01:10:00:12"),
+            Err(ParseDetail::MissingFramerate)
+        );
+        assert_eq!(
+            Timecode::parse_detailed("01:10:00:12@"),
+            Err(ParseDetail::BadFramerate(String::new()))
+        );
+        assert_eq!(
+            Timecode::parse_detailed("01:10:00:12@fast"),
+            Err(ParseDetail::BadFramerate("fast".to_string()))
+        );
+        assert_eq!(
+            Timecode::parse_detailed("01:10:00 12@30"),
+            Err(ParseDetail::BadTimecode(TimecodeValidationError::Unparsed))
+        );
+        assert_eq!(
+            Timecode::parse_detailed("01:10:00:30@30"),
+            Err(ParseDetail::BadTimecode(
+                TimecodeValidationError::InvalidFrames(30)
+            ))
+        );
+    }
+
     #[test]
     fn dyns_mismatch() {
         let t1: Timecode<DynFramerate> = "01:10:00:12@30".parse().unwrap();
@@ -538,8 +2157,8 @@ mod add_test {
     fn dyn_downcast() {
         let t1: Timecode<DynFramerate> = "01:10:00:12@30".parse().unwrap();
         let tf = *t1.framerate();
-        let _: NDF<30> = tf.try_into().unwrap();
-        let _ = TryInto::<NDF<25>>::try_into(tf).unwrap_err();
+        TryInto::<NDF<30>>::try_into(tf).unwrap();
+        TryInto::<NDF<25>>::try_into(tf).unwrap_err();
     }
 
     #[test]
@@ -558,3 +2177,881 @@ mod add_test {
         //assert_eq!(t2, t1);
     }
 }
+
+#[cfg(test)]
+mod legal_test {
+    use super::*;
+
+    #[test]
+    fn legal_near_df_minute() {
+        let before = Timecode::legal_at_or_before(1799, &DF::<30>);
+        let after = Timecode::legal_at_or_after(1799, &DF::<30>);
+        assert_eq!(before.to_string(), "00:00:59;29");
+        assert_eq!(after.to_string(), "00:00:59;29");
+
+        let before = Timecode::legal_at_or_before(1800, &DF::<30>);
+        let after = Timecode::legal_at_or_after(1800, &DF::<30>);
+        assert_eq!(before.to_string(), "00:01:00;02");
+        assert_eq!(after.to_string(), "00:01:00;02");
+    }
+
+    #[test]
+    fn from_frame_str() {
+        let tc = Timecode::from_frame_str("1800", &DF::<30>).unwrap();
+        assert_eq!(tc.to_string(), "00:01:00;02");
+
+        for bad in ["", "-1", "1.5", "00:01:00;02", "99999999999"] {
+            assert_eq!(
+                Timecode::from_frame_str(bad, &DF::<30>),
+                Err(TimecodeValidationError::Unparsed)
+            );
+        }
+    }
+
+    #[test]
+    fn from_frame_f64() {
+        assert_eq!(
+            Timecode::from_frame_f64(1234.7, &NDF::<30>).to_frame_count(),
+            1235
+        );
+        assert_eq!(
+            Timecode::from_frame_f64(1234.4, &NDF::<30>).to_frame_count(),
+            1234
+        );
+        assert_eq!(
+            Timecode::from_frame_f64(-0.4, &NDF::<30>).to_string(),
+            "00:00:00:00"
+        );
+        assert_eq!(
+            Timecode::from_frame_f64(-100.0, &NDF::<30>).to_string(),
+            "00:00:00:00"
+        );
+        assert_eq!(
+            Timecode::from_frame_f64(1799.6, &DF::<30>).to_string(),
+            "00:01:00;02"
+        );
+    }
+
+    #[test]
+    fn byte_offset_round_trip() {
+        let bytes_per_frame = 1_382_400;
+
+        let tc: Timecode<DF<30>> = "10:00:00;00".parse().unwrap();
+        let offset = tc.to_byte_offset(bytes_per_frame);
+        assert_eq!(offset, 1_078_920 * bytes_per_frame);
+        assert_eq!(
+            Timecode::from_byte_offset(offset, bytes_per_frame, &DF::<30>),
+            tc
+        );
+        assert_eq!(
+            Timecode::from_byte_offset(offset + bytes_per_frame - 1, bytes_per_frame, &DF::<30>),
+            tc
+        );
+        assert_eq!(
+            Timecode::from_byte_offset(offset - 1, bytes_per_frame, &DF::<30>).to_string(),
+            "09:59:59;29"
+        );
+    }
+
+    #[test]
+    fn from_seconds_and_frame() {
+        let tc = Timecode::from_seconds_and_frame(60, 2, &DF::<30>).unwrap();
+        assert_eq!(tc.to_string(), "00:01:00;02");
+
+        let tc = Timecode::from_seconds_and_frame(600, 0, &DF::<30>).unwrap();
+        assert_eq!(tc.to_string(), "00:10:00;00");
+
+        assert_eq!(
+            Timecode::from_seconds_and_frame(60, 1, &DF::<30>),
+            Err(TimecodeValidationError::InvalidFrames(1))
+        );
+        assert_eq!(
+            Timecode::from_seconds_and_frame(5, 25, &NDF::<25>),
+            Err(TimecodeValidationError::InvalidFrames(25))
+        );
+        assert_eq!(
+            Timecode::from_seconds_and_frame(u32::MAX, 0, &NDF::<25>),
+            Err(TimecodeValidationError::Unparsed)
+        );
+    }
+}
+
+#[cfg(test)]
+mod eq_test {
+    use super::*;
+
+    #[test]
+    fn eq_ignoring_separator() {
+        let df: Timecode<DF<30>> = "00:10:00;00".parse().unwrap();
+        let dynamic = Timecode::new_with_fr("00:10:00;00", "29.97").unwrap();
+        let ndf: Timecode<NDF<30>> = "00:10:00:00".parse().unwrap();
+
+        //PartialEq only works with a DynFramerate on the left
+        assert_eq!(dynamic, df);
+        assert!(df.eq_ignoring_separator(&dynamic));
+        assert!(dynamic.eq_ignoring_separator(&df));
+
+        //same address, but 30fps is not the same speed as 29.97fps
+        assert!(!df.eq_ignoring_separator(&ndf));
+
+        assert!(df.eq_ignoring_separator(&df.with_separator(Some(parser::Seperator::Colon))));
+        assert!(!df.eq_ignoring_separator(&(df + Frames(1))));
+    }
+
+    #[test]
+    fn hash_matches_eq() {
+        use std::collections::HashMap;
+
+        let a = Timecode::new_with_fr("00:10:00;00", "29.97").unwrap();
+        let b = Timecode::new_with_fr("00:10:00:00", "29.97").unwrap();
+        let c = Timecode::new_with_fr("00:10:00:00", "30").unwrap();
+
+        let mut events = HashMap::new();
+        *events.entry(a).or_insert(0) += 1;
+        *events.entry(b).or_insert(0) += 1;
+        *events
+            .entry(a.with_separator(Some(parser::Seperator::Period)))
+            .or_insert(0) += 1;
+        *events.entry(c).or_insert(0) += 1;
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[&a], 3);
+        assert_eq!(events[&c], 1);
+    }
+
+    #[test]
+    fn close_to() {
+        let tc: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+        let pal: Timecode<NDF<25>> = tc.convert();
+
+        assert!(tc.close_to(&pal, 1));
+        assert!(pal.close_to(&tc, 1));
+        assert!(!tc.close_to(&(pal + Frames(2)), 1));
+        assert!(tc.close_to(&(pal + Frames(2)), 3));
+
+        let a: Timecode<NDF<25>> = "00:00:01:00".parse().unwrap();
+        assert!(a.close_to(&a, 0));
+        assert!(!a.close_to(&(a + Frames(1)), 0));
+        assert!(a.close_to(&(a + Frames(1)), 1));
+    }
+}
+
+#[cfg(test)]
+mod broadcast_test {
+    use super::*;
+
+    #[test]
+    fn broadcast_start() {
+        for tc in ["00:00:00;00", "01:00:00;00"] {
+            let tc: Timecode<DF<30>> = tc.parse().unwrap();
+            assert_eq!(tc.check_broadcast_start(), Ok(()));
+        }
+
+        for tc in ["02:00:00;00", "01:00:00;02", "00:59:59;29", "01:01:00;02"] {
+            let tc: Timecode<DF<30>> = tc.parse().unwrap();
+            assert_eq!(
+                tc.check_broadcast_start(),
+                Err(BroadcastError::NotHourStart)
+            );
+        }
+
+        let tc: Timecode<NDF<60>> = "01:00:00:00".parse().unwrap();
+        assert_eq!(
+            tc.check_broadcast_start(),
+            Err(BroadcastError::NotDropFrame)
+        );
+
+        let tc: Timecode<NDF<25>> = "01:00:00:00".parse().unwrap();
+        assert_eq!(tc.check_broadcast_start(), Ok(()));
+    }
+}
+
+#[cfg(test)]
+mod ord_test {
+    use super::*;
+
+    #[test]
+    fn cmp_realtime_cross_rate() {
+        use std::cmp::Ordering;
+
+        let pal: Timecode<NDF<25>> = "00:00:10:00".parse().unwrap();
+        let ndf: Timecode<NDF<30>> = "00:00:10:00".parse().unwrap();
+        assert_eq!(pal.cmp_realtime(&ndf), Ordering::Equal);
+        assert_eq!(pal.cmp_realtime(&(ndf + Frames(1))), Ordering::Less);
+        assert_eq!((pal + Frames(1)).cmp_realtime(&ndf), Ordering::Greater);
+
+        //10 seconds of 29.97 is 10.01 real seconds
+        let df: Timecode<DF<30>> = "00:00:10;00".parse().unwrap();
+        assert_eq!(ndf.cmp_realtime(&df), Ordering::Less);
+        assert_eq!(df.cmp_realtime(&ndf), Ordering::Greater);
+    }
+
+    #[test]
+    fn ord_ndf() {
+        let a: Timecode<NDF<30>> = "01:00:00:00".parse().unwrap();
+        let b: Timecode<NDF<30>> = "00:59:59:29".parse().unwrap();
+        assert!(a > b);
+        assert!(b < a);
+        assert_eq!(a.cmp(&a), std::cmp::Ordering::Equal);
+        assert_eq!(a.max(b), a);
+    }
+
+    #[test]
+    fn ord_df_boundary() {
+        let mut tcs: Vec<Timecode<DF<30>>> = ["00:01:00;02", "00:00:59;29", "00:01:00;03"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        tcs.sort();
+
+        let tcs: Vec<_> = tcs.iter().map(|tc| tc.to_string()).collect();
+        assert_eq!(tcs, ["00:00:59;29", "00:01:00;02", "00:01:00;03"]);
+    }
+
+    #[test]
+    fn ord_mixed_dyn() {
+        let ntsc = Timecode::new_with_fr("01:00:00;00", "29.97").unwrap();
+        let pal = Timecode::new_with_fr("01:00:00:00", "25").unwrap();
+        let ndf = Timecode::new_with_fr("01:00:00:00", "30").unwrap();
+
+        //01:00:00;00 at 29.97 is 107892 frames, which is 59:59.9964 in real time
+        assert!(ntsc < pal);
+        assert!(pal == ndf.convert_with_fr(&DynFramerate::new_ndf(25)));
+        assert_eq!(pal.cmp(&ndf), std::cmp::Ordering::Less);
+
+        let zero_a = Timecode::new_with_fr("00:00:00:00", "25").unwrap();
+        let zero_b = Timecode::new_with_fr("00:00:00:00", "30").unwrap();
+        assert_ne!(zero_a.cmp(&zero_b), std::cmp::Ordering::Equal);
+        assert_eq!(zero_a.cmp(&zero_b), zero_b.cmp(&zero_a).reverse());
+    }
+}
+
+#[cfg(test)]
+mod duration_test {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn samples() {
+        //01:00:00;00 is 107892 frames, each lasting 1001/30000 seconds
+        let tc: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+        assert_eq!(tc.to_samples(48000), 172_799_827);
+        assert_eq!(Timecode::from_samples(172_799_827, 48000, &DF::<30>), tc);
+
+        //108000 frames of 29.97 is 3600 * 1001 / 1000 real seconds
+        let tc = Timecode::from_frames(&Frames(108000), &DF::<30>);
+        assert_eq!(tc.to_samples(48000), 48000 * 3600 * 1001 / 1000);
+
+        let tc: Timecode<NDF<30>> = "01:00:00:00".parse().unwrap();
+        assert_eq!(tc.to_samples(48000), 48000 * 3600);
+        assert_eq!(tc.to_samples(44100), 44100 * 3600);
+
+        //1600 samples per frame at 30fps, so 800 rounds up and 799 rounds down
+        assert_eq!(Timecode::from_samples(800, 48000, &NDF::<30>).f(), 1);
+        assert_eq!(Timecode::from_samples(799, 48000, &NDF::<30>).f(), 0);
+    }
+
+    #[test]
+    fn as_seconds_f64() {
+        let tc: Timecode<NDF<30>> = "01:00:00:00".parse().unwrap();
+        assert_eq!(tc.as_seconds_f64(), 3600.0);
+
+        let tc: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+        assert!((tc.as_seconds_f64() - 3600.0).abs() < 0.01);
+        assert!((tc.as_seconds_f64() - 3599.9964).abs() < 1e-9);
+        assert_eq!(tc.as_seconds_f64(), tc.to_duration().as_secs_f64());
+    }
+
+    fn round_trip<FR: Framerate + std::fmt::Debug>(fr: FR) {
+        for count in [0, 1, 1799, 1800, 17982, 107892, 2_589_407] {
+            let tc = Timecode::from_frames(&Frames(count), &fr);
+            assert_eq!(Timecode::from_duration(tc.to_duration(), &fr), tc);
+        }
+    }
+
+    #[test]
+    fn duration_round_trip() {
+        round_trip(NDF::<30>);
+        round_trip(DF::<30>);
+        round_trip(NDF::<25>);
+    }
+
+    #[test]
+    fn ms_strings() {
+        let tc = Timecode::from_ms(999, &NDF::<30>);
+        assert_eq!(tc.to_string(), "00:00:01:00");
+
+        let tc = Timecode::from_ms(3_600_000, &DF::<30>);
+        assert_eq!(tc.to_string(), "01:00:00;00");
+        assert_eq!(tc.to_ms_string(), "00:59:59.996");
+
+        //17982 frames at 29.97 is 599.9994 seconds
+        let tc: Timecode<DF<30>> = "00:10:00;00".parse().unwrap();
+        assert_eq!(tc.to_ms_string(), "00:09:59.999");
+
+        let tc: Timecode<NDF<30>> = "00:00:00:01".parse().unwrap();
+        assert_eq!(tc.to_ms_string(), "00:00:00.033");
+        assert_eq!(Timecode::from_ms(33, &NDF::<30>), tc);
+    }
+
+    #[test]
+    fn from_duration_rounds() {
+        let fr = NDF::<25>;
+        assert_eq!(
+            Timecode::from_duration(Duration::from_millis(19), &fr).to_frame_count(),
+            0
+        );
+        assert_eq!(
+            Timecode::from_duration(Duration::from_millis(20), &fr).to_frame_count(),
+            1
+        );
+
+        let tc = Timecode::from_duration(Duration::from_secs(3600), &DF::<30>);
+        assert_eq!(tc.to_string(), "01:00:00;00");
+    }
+}
+
+#[cfg(test)]
+mod fingerprint_test {
+    use super::*;
+
+    #[test]
+    fn fingerprint_is_stable() {
+        let tc: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+        assert_eq!(tc.fingerprint(), 15370030447134907391);
+    }
+
+    #[test]
+    fn fingerprint_distinguishes_framerates() {
+        let df: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+        let ndf: Timecode<NDF<30>> = "01:00:00:00".parse().unwrap();
+        let dynamic: Timecode<DynFramerate> = "01:00:00;00@29.97".parse().unwrap();
+
+        assert_ne!(df.fingerprint(), ndf.fingerprint());
+        assert_eq!(df.fingerprint(), dynamic.fingerprint());
+        assert_eq!(
+            df.fingerprint(),
+            df.with_separator(Some(parser::Seperator::Colon))
+                .fingerprint()
+        );
+    }
+}
+
+#[cfg(test)]
+mod db_test {
+    use super::*;
+
+    #[test]
+    fn db_i64_round_trip() {
+        let tcs = [
+            Timecode::new_with_fr("00:00:00:00", "25").unwrap(),
+            Timecode::new_with_fr("23:59:59:24", "25").unwrap(),
+            Timecode::new_with_fr("01:00:00:00", "50").unwrap(),
+            Timecode::new_with_fr("00:01:00;02", "29.97").unwrap(),
+            Timecode::new_with_fr("10:00:00;00", "59.94").unwrap(),
+            Timecode::new_with_fr("12:34:56:23", "23.98").unwrap(),
+            Timecode::new_with_fr("00:00:00:119", "120").unwrap(),
+        ];
+
+        for tc in tcs {
+            let packed = tc.to_db_i64();
+            assert!(packed >= 0);
+            assert_eq!(Timecode::from_db_i64(packed).unwrap(), tc);
+        }
+
+        let a = Timecode::new_with_fr("00:00:59;29", "29.97").unwrap();
+        assert!(a.to_db_i64() < (a + Frames(1)).to_db_i64());
+    }
+
+    #[test]
+    fn db_i64_invalid() {
+        assert_eq!(
+            Timecode::from_db_i64(-1),
+            Err(TimecodeValidationError::Unparsed)
+        );
+        assert_eq!(
+            Timecode::from_db_i64(1 << 50),
+            Err(TimecodeValidationError::Unparsed)
+        );
+        assert_eq!(
+            Timecode::from_db_i64(0),
+            Err(TimecodeValidationError::InvalidFramerate(None))
+        );
+        assert_eq!(
+            Timecode::from_db_i64((1 << 48) | (25 << 32)),
+            Err(TimecodeValidationError::InvalidFramerate(None))
+        );
+    }
+}
+
+#[cfg(test)]
+mod gop_test {
+    use super::*;
+
+    #[test]
+    fn gop_15_ndf() {
+        let cases = [
+            ("00:00:00:00", "00:00:00:00", "00:00:00:15"),
+            ("00:00:00:14", "00:00:00:00", "00:00:00:15"),
+            ("00:00:00:15", "00:00:00:15", "00:00:01:00"),
+            ("00:00:00:29", "00:00:00:15", "00:00:01:00"),
+            ("01:00:00:07", "01:00:00:00", "01:00:00:15"),
+        ];
+
+        for (input, aligned, next) in cases {
+            let tc: Timecode<NDF<30>> = input.parse().unwrap();
+            assert_eq!(tc.gop_align(15).to_string(), aligned);
+            assert_eq!(tc.next_gop(15).to_string(), next);
+            assert_eq!(tc.gop_align(15).to_frame_count() % 15, 0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn gop_zero() {
+        let tc: Timecode<NDF<30>> = "00:00:00:00".parse().unwrap();
+        let _ = tc.gop_align(0);
+    }
+}
+
+#[cfg(test)]
+mod snap_test {
+    use super::*;
+
+    fn prev_next(tc: &str) -> (String, String) {
+        let tc: Timecode<DF<30>> = tc.parse().unwrap();
+        (
+            tc.snap_prev_second_boundary().to_string(),
+            tc.snap_next_second_boundary().to_string(),
+        )
+    }
+
+    #[test]
+    fn snap_df() {
+        assert_eq!(
+            prev_next("00:00:59;15"),
+            ("00:00:59;00".into(), "00:01:00;02".into())
+        );
+        assert_eq!(
+            prev_next("00:01:00;02"),
+            ("00:01:00;02".into(), "00:01:01;00".into())
+        );
+        assert_eq!(
+            prev_next("00:09:59;29"),
+            ("00:09:59;00".into(), "00:10:00;00".into())
+        );
+        assert_eq!(
+            prev_next("00:10:00;01"),
+            ("00:10:00;00".into(), "00:10:01;00".into())
+        );
+    }
+
+    #[test]
+    fn frames_into_unit() {
+        let tc: Timecode<DF<30>> = "01:01:59;29".parse().unwrap();
+        assert_eq!(tc.frames_into_minute(), 1797);
+        assert_eq!(tc.frames_into_hour(), 1800 + 1797);
+
+        let tc: Timecode<DF<30>> = "01:10:59;29".parse().unwrap();
+        assert_eq!(tc.frames_into_minute(), 1799);
+        assert_eq!(tc.frames_into_hour(), 17982 + 1799);
+
+        let tc: Timecode<DF<30>> = "01:01:00;02".parse().unwrap();
+        assert_eq!(tc.frames_into_minute(), 0);
+
+        let tc: Timecode<NDF<30>> = "01:01:59:29".parse().unwrap();
+        assert_eq!(tc.frames_into_minute(), 1799);
+        assert_eq!(tc.frames_into_hour(), 1800 + 1799);
+    }
+
+    #[test]
+    fn snap_ndf() {
+        let tc: Timecode<NDF<30>> = "00:00:59:15".parse().unwrap();
+        assert_eq!(tc.snap_prev_second_boundary().to_string(), "00:00:59:00");
+        assert_eq!(tc.snap_next_second_boundary().to_string(), "00:01:00:00");
+    }
+}
+
+#[cfg(test)]
+mod day_test {
+    use super::*;
+
+    #[test]
+    fn until_midnight() {
+        let tc: Timecode<NDF<30>> = "23:59:59:29".parse().unwrap();
+        assert_eq!(tc.until_midnight().to_string(), "00:00:00:01");
+
+        let tc: Timecode<NDF<30>> = "12:00:00:00".parse().unwrap();
+        assert_eq!(tc.until_midnight().to_string(), "12:00:00:00");
+    }
+
+    #[test]
+    fn until_midnight_at_midnight() {
+        let tc: Timecode<NDF<30>> = "00:00:00:00".parse().unwrap();
+        assert_eq!(tc.until_midnight().to_string(), "24:00:00:00");
+        assert_eq!(
+            tc.until_midnight().to_frame_count(),
+            frames_per_day(&NDF::<30>)
+        );
+    }
+
+    #[test]
+    fn max_frame_count_24h() {
+        let max = Timecode::max_frame_count_24h(&DF::<30>);
+        assert_eq!(max, 2589407);
+        let tc = Timecode::from_frames(&Frames(max), &DF::<30>);
+        assert_eq!(tc.to_string(), "23:59:59;29");
+
+        let max = Timecode::max_frame_count_24h(&NDF::<60>);
+        assert_eq!(max, 24 * 60 * 60 * 60 - 1);
+        let tc = Timecode::from_frames(&Frames(max), &NDF::<60>);
+        assert_eq!(tc.to_string(), "23:59:59:59");
+    }
+
+    #[test]
+    fn wrapping() {
+        let tc: Timecode<NDF<30>> = "23:59:59:29".parse().unwrap();
+        assert_eq!(tc.add_wrapping(Frames(1)).to_string(), "00:00:00:00");
+        assert_eq!(tc.add_wrapping(Frames(31)).to_string(), "00:00:01:00");
+        assert_eq!(tc.add_wrapping(Frames(0)), tc);
+
+        let zero: Timecode<NDF<30>> = "00:00:00:00".parse().unwrap();
+        assert_eq!(zero.sub_wrapping(Frames(1)), tc);
+        assert_eq!(zero.sub_wrapping(Frames(frames_per_day(&NDF::<30>))), zero);
+        assert_eq!(
+            zero.add_wrapping(Frames(frames_per_day(&NDF::<30>) * 3)),
+            zero
+        );
+
+        let tc: Timecode<DF<30>> = "00:00:00;00".parse().unwrap();
+        assert_eq!(tc.sub_wrapping(Frames(1)).to_string(), "23:59:59;29");
+        assert_eq!(tc.add_wrapping(Frames(u32::MAX)).to_frame_count(), {
+            (u32::MAX as u64 % frames_per_day(&DF::<30>) as u64) as FrameCount
+        });
+    }
+
+    #[test]
+    fn until_midnight_past_day() {
+        let tc: Timecode<NDF<30>> = "25:00:00:00".parse().unwrap();
+        assert_eq!(tc.until_midnight().to_string(), "00:00:00:00");
+    }
+}
+
+#[cfg(test)]
+mod display_test {
+    use super::*;
+
+    #[test]
+    fn styles_df() {
+        let tc: Timecode<DF<30>> = "01:02:03;04".parse().unwrap();
+
+        let styled = |style| tc.display_with_style(style).to_string();
+        assert_eq!(styled(SeparatorStyle::ColonSemicolon), "01:02:03;04");
+        assert_eq!(styled(SeparatorStyle::ColonComma), "01:02:03,04");
+        assert_eq!(styled(SeparatorStyle::AllColon), "01:02:03:04");
+        assert_eq!(styled(SeparatorStyle::ColonSemicolon), tc.to_string());
+    }
+
+    #[test]
+    fn separator_override() {
+        let tc: Timecode<NDF<30>> = "01:02:03:04".parse().unwrap();
+        let forced = tc.with_separator(Some(parser::Seperator::Semicolon));
+
+        assert_eq!(forced.to_string(), "01:02:03;04");
+        assert_eq!(forced, tc);
+        assert_eq!(forced.with_separator(None).to_string(), "01:02:03:04");
+    }
+
+    #[test]
+    fn separator_override_invalid() {
+        use std::convert::TryFrom;
+
+        let tc: Timecode<NDF<30>> = "01:02:03:04".parse().unwrap();
+        let forced = parser::Seperator::try_from('x').map(|sep| tc.with_separator(Some(sep)));
+        assert!(forced.is_err());
+
+        let forced = tc.with_separator(Some(parser::Seperator::Period));
+        assert_eq!(forced.to_string(), "01:02:03.04");
+        assert_eq!(forced.to_string().parse::<Timecode<NDF<30>>>().unwrap(), tc);
+    }
+
+    #[test]
+    fn separator_override_convert() {
+        let tc: Timecode<NDF<30>> = "01:00:00:00".parse().unwrap();
+
+        let converted: Timecode<NDF<25>> = tc
+            .with_separator(Some(parser::Seperator::Semicolon))
+            .convert();
+        assert_eq!(converted.to_string(), "01:00:00;00");
+
+        let start: Timecode<NDF<30>> = "00:00:00:00".parse().unwrap();
+        let converted: Timecode<NDF<25>> = tc
+            .with_separator(Some(parser::Seperator::Semicolon))
+            .convert_with_start(&start);
+        assert_eq!(converted.to_string(), "01:00:00;00");
+
+        let converted: Timecode<NDF<25>> = tc.convert();
+        assert_eq!(converted.to_string(), "01:00:00:00");
+    }
+
+    #[test]
+    fn styles_ndf() {
+        let tc: Timecode<NDF<25>> = "01:02:03:04".parse().unwrap();
+
+        let styled = |style| tc.display_with_style(style).to_string();
+        assert_eq!(styled(SeparatorStyle::ColonSemicolon), "01:02:03:04");
+        assert_eq!(styled(SeparatorStyle::ColonComma), "01:02:03:04");
+        assert_eq!(styled(SeparatorStyle::AllColon), "01:02:03:04");
+    }
+}
+
+#[cfg(test)]
+mod resample_test {
+    use super::*;
+
+    #[test]
+    fn pulldown_cadence() {
+        let expected = [0, 1, 2, 3, 5, 6, 7, 8, 10, 11];
+
+        for (film, video) in expected.into_iter().enumerate() {
+            let tc = Timecode::from_frames(&Frames(film as FrameCount), &NDF::<24>);
+            assert_eq!(tc.pulldown_2_3().to_frame_count(), video);
+        }
+
+        let tc: Timecode<NDF<24>> = "00:00:01:00".parse().unwrap();
+        assert_eq!(tc.pulldown_2_3().to_string(), "00:00:01;00");
+    }
+
+    #[test]
+    fn common_grid_30_25() {
+        let cases = [
+            ("00:00:00:00", "00:00:00:00"),
+            ("00:00:00:02", "00:00:00:00"),
+            ("00:00:00:03", "00:00:00:06"),
+            ("00:00:00:08", "00:00:00:06"),
+            ("00:00:00:28", "00:00:01:00"),
+        ];
+
+        for (input, snapped) in cases {
+            let tc: Timecode<NDF<30>> = input.parse().unwrap();
+            let grid = tc.common_grid_instant(&NDF::<25>);
+            assert_eq!(grid.to_string(), snapped);
+
+            let there: Timecode<NDF<25>> = grid.convert();
+            let back: Timecode<NDF<30>> = there.convert();
+            assert_eq!(back, grid);
+        }
+    }
+
+    #[test]
+    fn flip_drop() {
+        let tc: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+
+        let ndf = tc.as_if_ndf();
+        assert_eq!(ndf.to_frame_count(), tc.to_frame_count());
+        assert_eq!(ndf.to_string(), "00:59:56:12");
+
+        let df = ndf.as_if_drop().unwrap();
+        assert_eq!(df, tc);
+        assert_eq!(df.to_string(), "01:00:00;00");
+
+        assert_eq!(tc.as_if_drop().unwrap(), tc);
+        assert!(Timecode::from_frames(&Frames(10), &NDF::<25>)
+            .as_if_drop()
+            .is_none());
+    }
+
+    #[test]
+    fn relabel_keeps_frame_count() {
+        let tc: Timecode<DF<30>> = "00:10:00;00".parse().unwrap();
+
+        let relabeled = tc.relabel(&NDF::<30>);
+        assert_eq!(relabeled.to_frame_count(), tc.to_frame_count());
+        assert_eq!(relabeled.to_string(), "00:09:59:12");
+
+        let converted: Timecode<NDF<30>> = tc.convert();
+        assert_eq!(converted.to_string(), "00:09:59:29");
+
+        let tc: Timecode<NDF<25>> = "01:00:00:00".parse().unwrap();
+        let relabeled = tc.relabel(&NDF::<50>);
+        assert_eq!(relabeled.to_frame_count(), tc.to_frame_count());
+        assert_eq!(relabeled.to_string(), "00:30:00:00");
+
+        let converted: Timecode<NDF<50>> = tc.convert();
+        assert_eq!(converted.to_string(), "01:00:00:00");
+        assert_eq!(converted.to_frame_count(), tc.to_frame_count() * 2);
+    }
+
+    #[test]
+    fn with_framerate() {
+        let tc: Timecode<DynFramerate> = "00:00:00:29@30".parse().unwrap();
+        assert_eq!(
+            tc.with_framerate(DynFramerate::new_ndf(25)),
+            Err(TimecodeValidationError::InvalidFrames(29))
+        );
+
+        let relabeled = tc.with_framerate(DynFramerate::new_ndf(60)).unwrap();
+        assert_eq!(relabeled.to_string(), "00:00:00:29");
+        assert_eq!(relabeled.framerate(), &DynFramerate::new_ndf(60));
+
+        let tc: Timecode<DynFramerate> = "00:01:00:00@30".parse().unwrap();
+        assert_eq!(
+            tc.with_framerate(DynFramerate::new_df(30)),
+            Err(TimecodeValidationError::InvalidFrames(0))
+        );
+    }
+
+    #[test]
+    fn upsample_25_50() {
+        let tc: Timecode<NDF<25>> = "00:00:01:24".parse().unwrap();
+        let up: Timecode<NDF<50>> = tc.upsample(2);
+
+        assert_eq!(up.to_string(), "00:00:01:48");
+        assert_eq!(up.to_frame_count(), tc.to_frame_count() * 2);
+
+        let up = tc.upsample_with_fr(&DynFramerate::new_ndf(50), 2);
+        assert_eq!(up.to_string(), "00:00:01:48");
+    }
+
+    #[test]
+    fn upsample_df() {
+        let tc: Timecode<DF<30>> = "00:01:00;02".parse().unwrap();
+        let up: Timecode<DF<60>> = tc.upsample(2);
+
+        assert_eq!(up.to_string(), "00:01:00;04");
+    }
+
+    #[test]
+    #[should_panic]
+    fn upsample_wrong_factor() {
+        let tc: Timecode<NDF<25>> = "00:00:01:24".parse().unwrap();
+        let _: Timecode<NDF<50>> = tc.upsample(3);
+    }
+
+    #[test]
+    fn downsample_50_25() {
+        let tc: Timecode<NDF<50>> = "00:00:01:48".parse().unwrap();
+        let (down, lost) = tc.downsample::<NDF<25>>(2);
+        assert_eq!(down.to_string(), "00:00:01:24");
+        assert_eq!(lost, 0);
+
+        let tc: Timecode<NDF<50>> = "00:00:01:49".parse().unwrap();
+        let (down, lost) = tc.downsample::<NDF<25>>(2);
+        assert_eq!(down.to_string(), "00:00:01:24");
+        assert_eq!(lost, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn downsample_wrong_direction() {
+        let tc: Timecode<NDF<25>> = "00:00:01:24".parse().unwrap();
+        let _ = tc.downsample::<NDF<50>>(2);
+    }
+}
+
+#[cfg(test)]
+mod frames_test {
+    use super::*;
+
+    #[test]
+    fn frames_parse_display() {
+        let frames: Frames = "1860".parse().unwrap();
+        assert_eq!(frames, Frames(1860));
+        assert_eq!(frames.to_string(), "1860");
+
+        assert!("-1".parse::<Frames>().is_err());
+        assert!("00:01:02:00".parse::<Frames>().is_err());
+    }
+}
+
+#[cfg(test)]
+mod convert_test {
+    use super::*;
+
+    #[test]
+    fn try_convert() {
+        let tc: Timecode<NDF<25>> = "01:00:00:00".parse().unwrap();
+        assert_eq!(
+            tc.try_convert_with_fr(&NDF::<50>),
+            Ok(tc.convert_with_fr(&NDF::<50>))
+        );
+
+        let big: Timecode<NDF<25>> = "255:00:00:00".parse().unwrap();
+        assert_eq!(
+            big.try_convert_with_fr(&NDF::<5000>),
+            Err(ConversionError::TooLarge)
+        );
+        assert!(big.try_convert_with_fr(&NDF::<24>).is_ok());
+    }
+
+    #[test]
+    fn try_convert_huge_framerate() {
+        let tc = Timecode::new_with_fr("200:00:00;00", "29.97").unwrap();
+        let huge = DynFramerate::new_ndf(FrameCount::MAX);
+
+        assert_eq!(
+            tc.try_convert_with_fr(&huge),
+            Err(ConversionError::TooLarge)
+        );
+        assert_eq!(
+            tc.try_convert_rounded(&huge, RoundMode::Nearest),
+            Err(ConversionError::TooLarge)
+        );
+
+        //a huge framerate still converts short timecodes exactly
+        let one = Timecode::from_frames(&Frames(1), &DynFramerate::new_ndf(FrameCount::MAX));
+        let back = one.try_convert_with_fr(&huge).unwrap();
+        assert_eq!(back.to_frame_count(), 1);
+    }
+
+    #[test]
+    fn frames_convert() {
+        assert_eq!(Frames(1800).convert(&NDF::<25>, &NDF::<30>), Frames(2160));
+        assert_eq!(Frames(2160).convert(&NDF::<30>, &NDF::<25>), Frames(1800));
+
+        let tc: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+        let converted: Timecode<NDF<24>> = tc.convert();
+        assert_eq!(
+            Frames(tc.to_frame_count()).convert(&DF::<30>, &NDF::<24>),
+            Frames(converted.to_frame_count())
+        );
+    }
+
+    #[test]
+    fn convert_rounded() {
+        //3 frames at 50fps is 1.5 frames at 25fps
+        let tc = Timecode::from_frames(&Frames(3), &NDF::<50>);
+        assert_eq!(
+            tc.convert_with_fr_rounded(&NDF::<25>, RoundMode::Floor).f(),
+            1
+        );
+        assert_eq!(
+            tc.convert_with_fr_rounded(&NDF::<25>, RoundMode::Ceil).f(),
+            2
+        );
+        assert_eq!(
+            tc.convert_with_fr_rounded(&NDF::<25>, RoundMode::Nearest)
+                .f(),
+            2
+        );
+
+        //halves go to the even frame
+        let tc = Timecode::from_frames(&Frames(5), &NDF::<50>);
+        assert_eq!(
+            tc.convert_with_fr_rounded(&NDF::<25>, RoundMode::Nearest)
+                .f(),
+            2
+        );
+
+        let tc = Timecode::from_frames(&Frames(4), &NDF::<50>);
+        for mode in [RoundMode::Floor, RoundMode::Ceil, RoundMode::Nearest] {
+            assert_eq!(tc.convert_with_fr_rounded(&NDF::<25>, mode).f(), 2);
+        }
+
+        let tc: Timecode<NDF<25>> = "01:00:00:00".parse().unwrap();
+        assert_eq!(
+            tc.convert_with_fr_rounded(&DF::<30>, RoundMode::Floor),
+            tc.convert_with_fr(&DF::<30>)
+        );
+    }
+}
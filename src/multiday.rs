@@ -0,0 +1,98 @@
+use crate::{frames_per_day, FrameCount, Framerate, Frames, Timecode, ToFrames};
+
+///A timecode that keeps counting past midnight, for recordings longer than 24 hours. The
+///timecode part always stays below `24:00:00:00`, and whole days are carried into `day`.
+///
+///```
+///# use timecode::{framerates::*, Timecode, Frames, multiday::MultiDayTimecode};
+///let tc: Timecode<NDF<30>> = "23:59:59:29".parse().unwrap();
+///let t = MultiDayTimecode::new(1, tc) + Frames(1);
+///
+///assert_eq!(t.day(), 2);
+///assert_eq!(t.to_string(), "2d 00:00:00:00");
+///```
+#[derive(Copy, Debug, Eq, PartialEq, Hash, Clone)]
+pub struct MultiDayTimecode<FR> {
+    day: u32,
+    tc: Timecode<FR>,
+}
+
+impl<FR: Framerate> MultiDayTimecode<FR> {
+    ///If `tc` is 24 hours or more, the extra days are added to `day`.
+    pub fn new(day: u32, tc: Timecode<FR>) -> Self {
+        let total = day as u64 * frames_per_day(tc.framerate()) as u64 + tc.to_frame_count() as u64;
+        Self::from_total_frames(total, tc.framerate())
+    }
+
+    ///Split a frame count spanning multiple days into days and a timecode.
+    pub fn from_total_frames(total: u64, fr: &FR) -> Self {
+        let per_day = frames_per_day(fr) as u64;
+        let day = (total / per_day).try_into().expect("Too large");
+        let rest = (total % per_day) as FrameCount;
+
+        Self {
+            day,
+            tc: Timecode::from_frames(&Frames(rest), fr),
+        }
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn tc(&self) -> &Timecode<FR> {
+        &self.tc
+    }
+
+    ///Number of frames since `00:00:00:00` on day 0
+    pub fn total_frames(&self) -> u64 {
+        self.day as u64 * frames_per_day(self.tc.framerate()) as u64
+            + self.tc.to_frame_count() as u64
+    }
+}
+
+impl<FR: Framerate> std::ops::Add<Frames> for MultiDayTimecode<FR> {
+    type Output = Self;
+
+    fn add(self, rhs: Frames) -> Self::Output {
+        Self::from_total_frames(self.total_frames() + rhs.0 as u64, self.tc.framerate())
+    }
+}
+
+impl<FR: Framerate> std::fmt::Display for MultiDayTimecode<FR> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}d {}", self.day, self.tc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framerates::*;
+
+    #[test]
+    fn rollover_midnight() {
+        let tc: Timecode<NDF<30>> = "23:59:59:00".parse().unwrap();
+        let t = MultiDayTimecode::new(0, tc);
+        assert_eq!(t.to_string(), "0d 23:59:59:00");
+
+        let t = t + Frames(45);
+        assert_eq!(t.day(), 1);
+        assert_eq!(t.to_string(), "1d 00:00:00:15");
+        assert_eq!(t.total_frames(), frames_per_day(&NDF::<30>) as u64 + 15);
+    }
+
+    #[test]
+    fn rollover_df() {
+        let tc: Timecode<DF<30>> = "23:59:59;29".parse().unwrap();
+        let t = MultiDayTimecode::new(2, tc) + Frames(1);
+        assert_eq!(t.to_string(), "3d 00:00:00;00");
+    }
+
+    #[test]
+    fn new_carries_days() {
+        let tc: Timecode<NDF<25>> = "49:00:00:00".parse().unwrap();
+        let t = MultiDayTimecode::new(1, tc);
+        assert_eq!(t.to_string(), "3d 01:00:00:00");
+    }
+}
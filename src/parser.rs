@@ -9,19 +9,22 @@ use nom::{
     IResult,
 };
 
-use crate::FrameCount;
+use crate::{DynFramerate, FrameCount, Framerate, Timecode};
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub enum Seperator {
     Semicolon,
     Colon,
+    ///Written by some European tools, as in `01:02:03.04`. Treated the same as `:`.
+    Period,
 }
 
-impl Into<char> for Seperator {
-    fn into(self) -> char {
-        match self {
-            Self::Semicolon => ';',
-            Self::Colon => ':',
+impl From<Seperator> for char {
+    fn from(sep: Seperator) -> char {
+        match sep {
+            Seperator::Semicolon => ';',
+            Seperator::Colon => ':',
+            Seperator::Period => '.',
         }
     }
 }
@@ -33,6 +36,7 @@ impl std::convert::TryFrom<char> for Seperator {
         match c {
             ';' => Ok(Self::Semicolon),
             ':' => Ok(Self::Colon),
+            '.' => Ok(Self::Period),
             _ => Err(()),
         }
     }
@@ -49,6 +53,64 @@ pub struct UnvalidatedTC {
     pub seperator: Seperator,
 }
 
+impl UnvalidatedTC {
+    ///Build an unvalidated timecode from its fields. `sep` must be `:`, `;`, or `.`.
+    ///
+    ///```
+    ///use timecode::parser::{Seperator, UnvalidatedTC};
+    ///
+    ///let tc = UnvalidatedTC::new(1, 2, 0, 25, ';').unwrap();
+    ///assert_eq!(tc.seperator, Seperator::Semicolon);
+    ///assert!(UnvalidatedTC::new(1, 2, 0, 25, '/').is_err());
+    ///```
+    //same error as TryFrom<char> for Seperator
+    #[allow(clippy::result_unit_err)]
+    pub fn new(h: u8, m: u8, s: u8, f: FrameCount, sep: char) -> Result<Self, ()> {
+        Ok(Self {
+            h,
+            m,
+            s,
+            f,
+            seperator: sep.try_into()?,
+        })
+    }
+
+    ///Build an unvalidated timecode from its fields, with the separator `fr` expects
+    ///
+    ///```
+    ///use timecode::{framerates::*, parser::UnvalidatedTC};
+    ///
+    ///let tc = UnvalidatedTC::for_framerate(1, 2, 0, 25, &DF::<30>);
+    ///assert_eq!(tc.sep_char(), ';');
+    ///assert_eq!(tc.validate::<DF<30>>().unwrap().to_string(), "01:02:00;25");
+    ///```
+    pub fn for_framerate(h: u8, m: u8, s: u8, f: FrameCount, fr: &impl Framerate) -> Self {
+        let seperator = if fr.is_dropframe() {
+            Seperator::Semicolon
+        } else {
+            Seperator::Colon
+        };
+
+        Self {
+            h,
+            m,
+            s,
+            f,
+            seperator,
+        }
+    }
+
+    ///True if the timecode was written with a `;`, as dropframe timecodes are
+    pub fn is_drop_style(&self) -> bool {
+        self.seperator == Seperator::Semicolon
+    }
+
+    ///The separator between seconds and frames, as it was written
+    pub fn sep_char(&self) -> char {
+        self.seperator.into()
+    }
+}
+
 impl std::str::FromStr for UnvalidatedTC {
     type Err = ();
 
@@ -62,7 +124,7 @@ impl std::str::FromStr for UnvalidatedTC {
 
 ///string to int for numbers <255
 fn from_dec(input: &str) -> Result<u64, std::num::ParseIntError> {
-    u64::from_str_radix(input, 10)
+    input.parse()
 }
 
 ///takes 2-3 digits from a timecode string and parse it into int
@@ -70,23 +132,27 @@ fn from_dec(input: &str) -> Result<u64, std::num::ParseIntError> {
 ///This may return an invalid value for seconds, minutes, or frames, so it is up to the user to
 ///validate after receiving this input.
 fn tc_digits<const SIZE: usize>(input: &str) -> IResult<&str, u64> {
-    map_res(take_while_m_n(2, SIZE, |c: char| c.is_digit(10)), from_dec)(input)
+    tc_digits_n(SIZE)(input)
 }
 
-fn tc_seperator(input: &str) -> IResult<&str, Seperator> {
-    //TODO get rid of the match statement somehow
-    let (input, sep) = satisfy(|c| c == ';' || c == ':')(input)?;
-
-    Ok((input, sep.try_into().unwrap()))
+///Same as tc_digits, but with the maximum number of digits known only at runtime
+fn tc_digits_n(size: usize) -> impl Fn(&str) -> IResult<&str, u64> {
+    move |input| {
+        map_res(
+            take_while_m_n(2, size, |c: char| c.is_ascii_digit()),
+            from_dec,
+        )(input)
+    }
 }
 
-pub fn timecode_nom(input: &str) -> IResult<&str, UnvalidatedTC> {
+///Parse the timecode fields, allowing up to `hms` digits for hours, minutes, and seconds, and up
+///to `frames` digits for frames.
+fn timecode_nom_sized(input: &str, hms: usize, frames: usize) -> IResult<&str, UnvalidatedTC> {
     let parse_timecode = tuple((
-        pair(tc_digits::<3>, char(':')),
-        pair(tc_digits::<3>, char(':')),
-        pair(tc_digits::<3>, tc_seperator),
-        //up to 10 digits for frames: TODO not to spec?
-        tc_digits::<10>,
+        pair(tc_digits_n(hms), char(':')),
+        pair(tc_digits_n(hms), char(':')),
+        pair(tc_digits_n(hms), tc_seperator),
+        tc_digits_n(frames),
     ))(input)?;
 
     //destructure into more readable format
@@ -111,11 +177,49 @@ pub fn timecode_nom(input: &str) -> IResult<&str, UnvalidatedTC> {
     ))
 }
 
+fn tc_seperator(input: &str) -> IResult<&str, Seperator> {
+    //TODO get rid of the match statement somehow
+    let (input, sep) = satisfy(|c| c == ';' || c == ':' || c == '.')(input)?;
+
+    Ok((input, sep.try_into().unwrap()))
+}
+
+///Widest hours, minutes, and seconds fields accepted by [`timecode_nom`]
+const HMS_DIGITS: usize = 3;
+///Widest frames field accepted by [`timecode_nom`]. TODO not to spec?
+const FRAME_DIGITS: usize = 10;
+
+pub fn timecode_nom(input: &str) -> IResult<&str, UnvalidatedTC> {
+    timecode_nom_sized(input, HMS_DIGITS, FRAME_DIGITS)
+}
+
+///Like [`timecode_nom`], but hours, minutes, and seconds must be exactly two digits, and the
+///frames field can be no wider than the largest frame number at `fr` (two digits below 100fps).
+///
+///```
+///use timecode::{framerates::*, parser::timecode_nom_strict};
+///
+///assert!(timecode_nom_strict("01:00:00:00", &NDF::<30>).is_ok());
+///assert!(timecode_nom_strict("100:00:00:00", &NDF::<30>).is_err());
+///assert!(timecode_nom_strict("01:00:00:100", &NDF::<120>).is_ok());
+///
+/////the frames field stops after two digits, leaving the last digit unparsed
+///let (remaining, _) = timecode_nom_strict("01:00:00:100", &NDF::<30>).unwrap();
+///assert_eq!(remaining, "0");
+///```
+pub fn timecode_nom_strict<'a>(
+    input: &'a str,
+    fr: &impl Framerate,
+) -> IResult<&'a str, UnvalidatedTC> {
+    timecode_nom_sized(input, 2, frame_digits(fr))
+}
+
 ///Returns an unvalidated timecode parsed into a struct iff it matches a valid timecode format
 ///
 /// Current valid formats:
 ///   HHH:MM:SS;FFF
 ///   HHH:MM:SS:FFF
+///   HHH:MM:SS.FFF
 ///
 /// hours, minutes, and seconds must be less than 256
 ///
@@ -173,29 +277,395 @@ pub fn unvalidated(input: &str) -> Option<UnvalidatedTC> {
         .flatten()
 }
 
+///Like [`unvalidated`], but anything after the timecode is returned instead of being an error.
+///
+///```
+///use timecode::parser::unvalidated_prefix;
+///
+///let (tc, rest) = unvalidated_prefix("01:23:12;22\tSceneA").unwrap();
+///assert_eq!(tc.f, 22);
+///assert_eq!(rest, "\tSceneA");
+///```
+pub fn unvalidated_prefix(input: &str) -> Option<(UnvalidatedTC, &str)> {
+    timecode_nom(input).ok().map(|(rest, tc)| (tc, rest))
+}
+
+///Where and why [`parse_detailed`] failed
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ParseError {
+    ///Byte offset into the input where parsing stopped
+    pub offset: usize,
+    ///What was expected at `offset`, such as `"expected ':'"`
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at position {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+///Same as [`unvalidated`], but on failure reports where the input stopped matching.
+///
+///```
+///use timecode::parser::parse_detailed;
+///
+///assert!(parse_detailed("01:23:12;22").is_ok());
+///
+///let err = parse_detailed("01-23:12;22").unwrap_err();
+///assert_eq!(err.offset, 2);
+///assert_eq!(err.to_string(), "expected ':' at position 2");
+///```
+pub fn parse_detailed(input: &str) -> Result<UnvalidatedTC, ParseError> {
+    match timecode_nom(input) {
+        Ok(("", tc)) => Ok(tc),
+        Ok((rest, _)) => Err(ParseError {
+            offset: input.len() - rest.len(),
+            message: "unexpected trailing characters".to_string(),
+        }),
+        //timecode_nom doesn't say where it failed, so find the first field that doesn't parse
+        Err(_) => Err(locate_error(input)),
+    }
+}
+
+///Walk the fields of a timecode that [`timecode_nom`] rejected, using the same field parsers,
+///and report the first one that fails. Only used for error messages.
+fn locate_error(input: &str) -> ParseError {
+    let error = |rest: &str, message: &str| ParseError {
+        offset: input.len() - rest.len(),
+        message: message.to_string(),
+    };
+
+    let field = |rest, size, name: &str| -> Result<(&str, u64), ParseError> {
+        tc_digits_n(size)(rest).map_err(|_| error(rest, &format!("expected {name} digits")))
+    };
+    let colon = |rest| -> Result<&str, ParseError> {
+        char::<_, nom::error::Error<&str>>(':')(rest)
+            .map(|(rest, _)| rest)
+            .map_err(|_| error(rest, "expected ':'"))
+    };
+    let byte = |v: u64, rest, name: &str| -> Result<(), ParseError> {
+        u8::try_from(v)
+            .map(|_| ())
+            .map_err(|_| error(rest, &format!("{name} must be less than 256")))
+    };
+
+    let walk = || -> Result<(), ParseError> {
+        let mut rest = input;
+        for name in ["hours", "minutes"] {
+            let (r, v) = field(rest, HMS_DIGITS, name)?;
+            byte(v, r, name)?;
+            rest = colon(r)?;
+        }
+
+        let (rest, s) = field(rest, HMS_DIGITS, "seconds")?;
+        byte(s, rest, "seconds")?;
+        let (rest, _) = tc_seperator(rest).map_err(|_| error(rest, "expected ':', ';' or '.'"))?;
+
+        let (rest, f) = field(rest, FRAME_DIGITS, "frames")?;
+        FrameCount::try_from(f).map_err(|_| error(rest, "frames are too large"))?;
+
+        Ok(())
+    };
+
+    //every field parsed, so the two disagree. Still report something rather than panicking.
+    walk()
+        .err()
+        .unwrap_or_else(|| error(input, "invalid timecode"))
+}
+
+///Like [`unvalidated`], but every field must be exactly two digits, as in SMPTE ST 12M.
+///
+///```
+///use timecode::parser::parse_strict_2digit;
+///
+///assert!(parse_strict_2digit("01:00:00:00").is_some());
+///assert!(parse_strict_2digit("100:00:00:00").is_none());
+///assert!(timecode::unvalidated("100:00:00:00").is_some());
+///```
+pub fn parse_strict_2digit(input: &str) -> Option<UnvalidatedTC> {
+    match timecode_nom_sized(input, 2, 2) {
+        Ok(("", tc)) => Some(tc),
+        _ => None,
+    }
+}
+
+///Parse a millisecond timestamp such as `01:02:03.500`, as used by subtitle formats, into hours,
+///minutes, seconds, and milliseconds. The milliseconds must be exactly three digits.
+///
+///```
+///use timecode::parser::parse_ms;
+///
+///assert_eq!(parse_ms("01:02:03.500"), Some((1, 2, 3, 500)));
+///assert_eq!(parse_ms("01:02:03:12"), None);
+///```
+pub fn parse_ms(input: &str) -> Option<(u8, u8, u8, u16)> {
+    let ms_digits = map_res(take_while_m_n(3, 3, |c: char| c.is_ascii_digit()), from_dec);
+
+    let (remaining, ((h, _), (m, _), (s, _), ms)) = tuple((
+        pair(tc_digits::<2>, char(':')),
+        pair(tc_digits::<2>, char(':')),
+        pair(tc_digits::<2>, char('.')),
+        ms_digits,
+    ))(input)
+    .ok()?;
+
+    if !remaining.is_empty() || m >= 60 || s >= 60 {
+        return None;
+    }
+
+    Some((
+        h.try_into().ok()?,
+        m.try_into().ok()?,
+        s.try_into().ok()?,
+        ms.try_into().ok()?,
+    ))
+}
+
+///Like [`unvalidated`], but the separator is replaced with the one `fr` expects. Validating the
+///result will then never produce a
+///[`MismatchSep`](crate::validate::TimecodeValidationWarning::MismatchSep) warning.
+///
+///```
+///use timecode::{framerates::DF, parser::{unvalidated_autocorrect, Seperator}};
+///
+///let tc = unvalidated_autocorrect("01:00:00:00", &DF::<30>).unwrap();
+///assert_eq!(tc.seperator, Seperator::Semicolon);
+///```
+pub fn unvalidated_autocorrect(s: &str, fr: &impl Framerate) -> Option<UnvalidatedTC> {
+    let UnvalidatedTC { h, m, s, f, .. } = unvalidated(s)?;
+    Some(UnvalidatedTC::for_framerate(h, m, s, f, fr))
+}
+
+///Number of digits needed to write the largest frame number at `fr`, but at least 2
+pub(crate) fn frame_digits(fr: &impl Framerate) -> usize {
+    fr.max_frame().saturating_sub(1).max(10).ilog10() as usize + 1
+}
+
+///Parse a fixed width timecode with no separators, like `01000000` for `01:00:00:00`.
+///
+///Hours, minutes, and seconds are always two digits. The frames field is as wide as the largest
+///frame number at `fr`, so 120fps timecodes need three frame digits (`010000119`).
+///
+///```
+///use timecode::{framerates::DynFramerate, parser::parse_packed};
+///
+///let tc = parse_packed("01020304", &DynFramerate::new_ndf(30)).unwrap();
+///assert_eq!(tc.to_string(), "01:02:03:04");
+///```
+pub fn parse_packed(input: &str, fr: &DynFramerate) -> Option<Timecode<DynFramerate>> {
+    let width = 6 + frame_digits(fr);
+    if input.len() != width || !input.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let tc = UnvalidatedTC::for_framerate(
+        input[0..2].parse().ok()?,
+        input[2..4].parse().ok()?,
+        input[4..6].parse().ok()?,
+        input[6..].parse().ok()?,
+        fr,
+    );
+
+    tc.validate_with_fr(fr).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn parse_basic_tc() {
-        assert!(matches!(timecode_nom("01:23:12;22"), Ok(_)));
-        assert!(matches!(unvalidated("01:23:12;22"), Some(_)));
+        assert!(timecode_nom("01:23:12;22").is_ok());
+        assert!(unvalidated("01:23:12;22").is_some());
     }
 
     #[test]
     fn parse_h_too_big() {
-        assert!(matches!(timecode_nom("911:00:00:00"), Err(_)));
+        assert!(timecode_nom("911:00:00:00").is_err());
+    }
+
+    #[test]
+    fn strict() {
+        use crate::framerates::*;
+
+        let (rest, tc) = timecode_nom_strict("23:59:59;29", &DF::<30>).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!((tc.h, tc.m, tc.s, tc.f), (23, 59, 59, 29));
+
+        assert!(timecode_nom_strict("123:23:23:00", &NDF::<30>).is_err());
+        assert!(timecode_nom_strict("01:123:23:00", &NDF::<30>).is_err());
+        assert!(timecode_nom_strict("01:23:123:00", &NDF::<30>).is_err());
+        assert!(timecode_nom_strict("1:23:23:00", &NDF::<30>).is_err());
+
+        let (rest, tc) = timecode_nom_strict("01:00:00:119", &DF::<120>).unwrap();
+        assert_eq!((rest, tc.f), ("", 119));
+        assert_ne!(
+            timecode_nom_strict("01:00:00:119", &NDF::<60>).unwrap().0,
+            ""
+        );
+    }
+
+    #[test]
+    fn detailed_errors() {
+        let err = |s| parse_detailed(s).unwrap_err();
+
+        assert_eq!(err("").offset, 0);
+        assert_eq!(err("0").message, "expected hours digits");
+        assert_eq!(err("01:2:00:00").offset, 3);
+        assert_eq!(err("0123:00:00:00").message, "expected ':'");
+        assert_eq!(err("0123:00:00:00").offset, 3);
+        assert_eq!(err("300:00:00:00").message, "hours must be less than 256");
+        assert_eq!(err("01:00:00-00").message, "expected ':', ';' or '.'");
+        assert_eq!(err("01:00:00-00").offset, 8);
+        assert_eq!(err("01:00:00:0").message, "expected frames digits");
+        assert_eq!(err("01:00:00:00 ").offset, 11);
+
+        assert_eq!(err("01:00:00:99999999999").message, "frames are too large");
+
+        for s in [
+            "01:23:12;22",
+            "911:00:00:00",
+            "01:00:00:00x",
+            "nope",
+            "01:02:03:04",
+            "255:255:255.4294967295",
+        ] {
+            assert_eq!(parse_detailed(s).ok(), unvalidated(s));
+        }
+
+        //any input timecode_nom rejects gets a located error, never the fallback
+        for s in [
+            "",
+            "0",
+            "01",
+            "01:",
+            "01:0",
+            "01:00",
+            "01:00:",
+            "01:00:0",
+            "01:00:00",
+            "01:00:00;",
+            "01:00:00;0",
+            "256:00:00:00",
+            "00:256:00:00",
+            "00:00:256:00",
+            "1:00:00:00",
+        ] {
+            assert_ne!(err(s).message, "invalid timecode", "{}", s);
+        }
     }
 
     #[test]
     fn trailing() {
-        assert!(matches!(timecode_nom("01:23:12;22 ok"), Ok(_)));
-        assert!(matches!(unvalidated("01:23:12;22 ok"), None));
+        assert!(timecode_nom("01:23:12;22 ok").is_ok());
+        assert!(unvalidated("01:23:12;22 ok").is_none());
+
+        let (tc, rest) = unvalidated_prefix("01:23:12;22 ok").unwrap();
+        assert_eq!(Some(tc), unvalidated("01:23:12;22"));
+        assert_eq!(rest, " ok");
+        assert_eq!(unvalidated_prefix("01:23:12;22").unwrap().1, "");
+        assert!(unvalidated_prefix("scene 01:23:12;22").is_none());
+    }
+
+    #[test]
+    fn drop_style() {
+        assert!(unvalidated("01:23:12;22").unwrap().is_drop_style());
+        assert!(!unvalidated("01:23:12:22").unwrap().is_drop_style());
+    }
+
+    #[test]
+    fn sep_char() {
+        assert_eq!(unvalidated("01:23:12;22").unwrap().sep_char(), ';');
+        assert_eq!(unvalidated("01:23:12:22").unwrap().sep_char(), ':');
     }
 
     #[test]
     fn wrong_sep() {
-        assert!(matches!(timecode_nom("123;23;23;00"), Err(_)));
+        assert!(timecode_nom("123;23;23;00").is_err());
+    }
+
+    #[test]
+    fn strict_2digit() {
+        assert!(parse_strict_2digit("01:23:12;22").is_some());
+        assert!(parse_strict_2digit("100:00:00:00").is_none());
+        assert!(unvalidated("100:00:00:00").is_some());
+        assert!(parse_strict_2digit("01:00:00:100").is_none());
+        assert!(parse_strict_2digit("01:00:000:00").is_none());
+        assert!(parse_strict_2digit("1:00:00:00").is_none());
+        assert!(parse_strict_2digit("01:00:00:0").is_none());
+        assert!(parse_strict_2digit("01:23:12;22 ok").is_none());
+    }
+
+    #[test]
+    fn ms_timestamps() {
+        assert_eq!(parse_ms("00:00:00.000"), Some((0, 0, 0, 0)));
+        assert_eq!(parse_ms("23:59:59.999"), Some((23, 59, 59, 999)));
+
+        assert_eq!(parse_ms("00:00:00.5"), None);
+        assert_eq!(parse_ms("00:00:00.5000"), None);
+        assert_eq!(parse_ms("00:60:00.000"), None);
+        assert_eq!(parse_ms("00:00:00;000"), None);
+    }
+
+    #[test]
+    fn period_separator() {
+        let tc = unvalidated("01:02:03.04").unwrap();
+        assert_eq!(tc.seperator, Seperator::Period);
+        assert_eq!(tc.sep_char(), '.');
+        assert!(!tc.is_drop_style());
+        assert_eq!(Seperator::try_from('.'), Ok(Seperator::Period));
+    }
+
+    #[test]
+    fn autocorrect() {
+        use crate::framerates::{DF, NDF};
+
+        let tc = unvalidated_autocorrect("00:01:00:02", &DF::<30>).unwrap();
+        assert_eq!(tc.seperator, Seperator::Semicolon);
+        let (_, warnings) = tc.validate_with_warnings::<DF<30>>().unwrap();
+        assert!(warnings.is_empty());
+
+        let tc = unvalidated_autocorrect("00:01:00;02", &NDF::<30>).unwrap();
+        assert_eq!(tc.seperator, Seperator::Colon);
+
+        let tc = unvalidated_autocorrect("00:01:00;02", &DF::<30>).unwrap();
+        assert_eq!(tc.seperator, Seperator::Semicolon);
+
+        assert!(unvalidated_autocorrect("00:01:00", &DF::<30>).is_none());
+    }
+
+    #[test]
+    fn packed() {
+        let fr = DynFramerate::new_ndf(30);
+        let tc = parse_packed("01000000", &fr).unwrap();
+        assert_eq!(tc.to_string(), "01:00:00:00");
+        assert_eq!(tc.framerate(), &fr);
+
+        assert!(parse_packed("01000030", &fr).is_none());
+        assert!(parse_packed("0100000", &fr).is_none());
+        assert!(parse_packed("010000000", &fr).is_none());
+        assert!(parse_packed("01:00:00", &fr).is_none());
+    }
+
+    #[test]
+    fn packed_df() {
+        let fr = DynFramerate::new_df(30);
+        assert_eq!(
+            parse_packed("00010002", &fr).unwrap().to_string(),
+            "00:01:00;02"
+        );
+        assert!(parse_packed("00010000", &fr).is_none());
+    }
+
+    #[test]
+    fn packed_high_rate() {
+        let fr = DynFramerate::new_ndf(120);
+        let tc = parse_packed("010000119", &fr).unwrap();
+        assert_eq!(tc.to_string(), "01:00:00:119");
+
+        assert!(parse_packed("01000011", &fr).is_none());
     }
 }
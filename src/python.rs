@@ -1,16 +1,21 @@
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::PyType;
 
 #[pymodule]
-fn timecode(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+fn timecode(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Timecode>()?;
     Ok(())
 }
 
 //rename Timecode to TC because I don't know how to rename a function for pyo3
-use crate::{Convert, DynFramerate, FrameCount, Framerate, Frames, Timecode as TC, ToFrames};
+use crate::{
+    validate::TimecodeValidationError, Convert, DynFramerate, FrameCount, Framerate, Frames,
+    Timecode as TC, ToFrames,
+};
 
-#[pyclass]
+#[pyclass(module = "timecode")]
 #[derive(Clone)]
 pub struct Timecode(TC<DynFramerate>);
 
@@ -19,18 +24,31 @@ impl Timecode {
     #[new]
     pub fn new(s: &str, fr: &str) -> PyResult<Timecode> {
         let d: DynFramerate = fr.parse().map_err(|e: &str| PyValueError::new_err(e))?;
-        match TC::new_with_fr(s, fr) {
-            Ok(tc) => return Ok(Timecode(tc)),
-            Err(_) => {}
-        };
+        if let Ok(tc) = TC::new_with_fr(s, fr) {
+            return Ok(Timecode(tc));
+        }
+
+        TC::from_frame_str(s, &d)
+            .map(Timecode)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    ///Like `Timecode(tc, fr)`, but also returns a list of warnings, such as a `:` separator on a
+    ///dropframe timecode. Does not accept frame counts.
+    #[staticmethod]
+    pub fn new_checked(tc: &str, fr: &str) -> PyResult<(Timecode, Vec<String>)> {
+        let d: DynFramerate = fr.parse().map_err(|e: &str| PyValueError::new_err(e))?;
+        let unvalidated = crate::unvalidated(tc)
+            .ok_or_else(|| PyValueError::new_err(TimecodeValidationError::Unparsed.to_string()))?;
 
-        let frames: FrameCount = match s.parse() {
-            Ok(frames) => frames,
-            Err(e) => return Err(PyValueError::new_err(e.to_string())),
-        };
+        let (tc, warnings) = unvalidated
+            .validate_with_warnings_fr(&d)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
 
-        let f = TC::from_frames(&Frames(frames), &d);
-        Ok(Timecode(f))
+        Ok((
+            Timecode(tc),
+            warnings.iter().map(|w| w.to_string()).collect(),
+        ))
     }
 
     pub fn __str__(&self) -> String {
@@ -41,6 +59,34 @@ impl Timecode {
         self.ts()
     }
 
+    ///`==` and `!=` are false and true for timecodes at different framerates. The ordering
+    ///operators raise a `ValueError` instead.
+    pub fn __richcmp__(&self, other: &Timecode, op: CompareOp) -> PyResult<bool> {
+        let same_fr = self.0.framerate() == other.0.framerate();
+        match op {
+            CompareOp::Eq => return Ok(self.0 == other.0),
+            CompareOp::Ne => return Ok(self.0 != other.0),
+            _ if !same_fr => return Err(PyValueError::new_err("Framerate mismatch")),
+            _ => {}
+        }
+
+        Ok(op.matches(self.0.to_frame_count().cmp(&other.0.to_frame_count())))
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    ///Pickle as the arguments to `Timecode(tc, fr)`
+    pub fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (String, String)) {
+        let tc = slf.borrow();
+        (slf.get_type(), (tc.ts(), tc.0.framerate().to_string()))
+    }
+
     pub fn ts(&self) -> String {
         format!("{}", self.0)
     }
@@ -49,15 +95,57 @@ impl Timecode {
         Timecode(self.0 + tc.0)
     }
 
-    pub fn add_frames(&self, frames: FrameCount) -> Timecode {
-        Timecode(self.0 + Frames(frames))
+    ///`tc + other` adds another timecode at the same framerate, or a number of frames
+    pub fn __add__(&self, other: &Bound<'_, PyAny>) -> PyResult<Timecode> {
+        if let Ok(tc) = other.extract::<Timecode>() {
+            if self.0.framerate() != tc.0.framerate() {
+                return Err(PyValueError::new_err("Framerate mismatch"));
+            }
+
+            return Ok(self.add(tc));
+        }
+
+        self.add_frames(other.extract()?)
     }
 
-    pub fn sub_frames(&self, frames: FrameCount) -> PyResult<Timecode> {
-        if self.0.to_frame_count() < frames {
-            return Err(PyValueError::new_err("Not enough frames"));
+    ///`tc - frames` moves back by a number of frames. `tc - other` is the number of frames
+    ///between two timecodes at the same framerate, which may be negative.
+    pub fn __sub__(&self, py: Python<'_>, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        if let Ok(tc) = other.extract::<Timecode>() {
+            if self.0.framerate() != tc.0.framerate() {
+                return Err(PyValueError::new_err("Framerate mismatch"));
+            }
+
+            let diff = self.0.to_frame_count() as i64 - tc.0.to_frame_count() as i64;
+            return Ok(diff.into_py(py));
         }
-        Ok(Timecode(self.0 - Frames(frames)))
+
+        Ok(self.sub_frames(other.extract()?)?.into_py(py))
+    }
+
+    pub fn add_frames(&self, frames: FrameCount) -> PyResult<Timecode> {
+        self.0
+            .add_frames(Frames(frames))
+            .map(Timecode)
+            .map_err(|_| PyValueError::new_err("Too many frames"))
+    }
+
+    pub fn sub_frames(&self, frames: FrameCount) -> PyResult<Timecode> {
+        self.0
+            .sub_frames(Frames(frames))
+            .map(Timecode)
+            .map_err(|_| PyValueError::new_err("Not enough frames"))
+    }
+
+    pub fn sub_frames_saturating(&self, frames: FrameCount) -> Timecode {
+        Timecode(self.0.saturating_sub_frames(Frames(frames)))
+    }
+
+    ///True if the two timecodes are within `frames` frames of each other in real time, counted at
+    ///the slower framerate.
+    #[pyo3(signature = (other, frames=1))]
+    pub fn close_to(&self, other: &Timecode, frames: FrameCount) -> bool {
+        self.0.close_to(&other.0, frames)
     }
 
     pub fn frame_count(&self) -> FrameCount {
@@ -74,7 +162,7 @@ impl Timecode {
         Ok(Self(self.0.convert_with_start_fr(&start.0, &d)))
     }
 
-    pub fn framerate(&self) -> f32 {
+    pub fn framerate(&self) -> f64 {
         self.0.framerate().fr_ratio()
     }
 
@@ -0,0 +1,345 @@
+use crate::{FrameCount, Framerate, Frames, Timecode, ToFrames};
+
+///A span of timecodes at a single framerate. `start` is included in the range, `end` is not.
+///
+///```
+///# use timecode::{framerates::*, Timecode, TimecodeRange, Frames};
+///let start: Timecode<NDF<30>> = "00:00:00:00".parse().unwrap();
+///let end: Timecode<NDF<30>> = "00:00:01:00".parse().unwrap();
+///
+///let range = TimecodeRange::new(start, end);
+///assert_eq!(range.duration(), Frames(30));
+///
+///let cues: Vec<String> = range.iter_frames(Frames(10)).map(|tc| tc.to_string()).collect();
+///assert_eq!(cues, ["00:00:00:00", "00:00:00:10", "00:00:00:20"]);
+///```
+#[derive(Copy, Debug, Eq, PartialEq, Hash, Clone)]
+pub struct TimecodeRange<FR> {
+    start: Timecode<FR>,
+    end: Timecode<FR>,
+}
+
+impl<FR: Framerate> TimecodeRange<FR> {
+    ///PANIC: if `end` is before `start`, or if the two framerates differ
+    pub fn new(start: Timecode<FR>, end: Timecode<FR>) -> Self {
+        assert!(
+            start.framerate() == end.framerate(),
+            "range endpoints must have the same framerate"
+        );
+        assert!(
+            start.to_frame_count() <= end.to_frame_count(),
+            "range end is before start"
+        );
+
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> &Timecode<FR> {
+        &self.start
+    }
+
+    pub fn end(&self) -> &Timecode<FR> {
+        &self.end
+    }
+
+    ///Number of frames between `start` and `end`
+    pub fn duration(&self) -> Frames {
+        Frames(self.end.to_frame_count() - self.start.to_frame_count())
+    }
+
+    ///The timecode `t` of the way from `start` to `end`, rounded to the nearest frame. `t` is
+    ///clamped to `0.0..=1.0`, so `1.0` gives `end`.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, TimecodeRange};
+    ///let start: Timecode<NDF<30>> = "00:00:00:00".parse().unwrap();
+    ///let end: Timecode<NDF<30>> = "00:00:10:00".parse().unwrap();
+    ///let range = TimecodeRange::new(start, end);
+    ///
+    ///assert_eq!(range.at_fraction(0.25).to_string(), "00:00:02:15");
+    ///```
+    pub fn at_fraction(&self, t: f64) -> Timecode<FR> {
+        let t = t.clamp(0.0, 1.0);
+        let offset = (self.duration().0 as f64 * t).round() as FrameCount;
+
+        Timecode::from_frames(
+            &Frames(self.start.to_frame_count() + offset),
+            self.start.framerate(),
+        )
+    }
+
+    ///Every whole second from `start` towards `end`. For dropframe ranges, seconds that start
+    ///on a dropped frame number yield the first legal frame instead, such as `00:01:00;02`.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, TimecodeRange};
+    ///let start: Timecode<NDF<25>> = "00:00:00:10".parse().unwrap();
+    ///let end: Timecode<NDF<25>> = "00:00:03:00".parse().unwrap();
+    ///
+    ///let ticks: Vec<String> = TimecodeRange::new(start, end)
+    ///    .iter_seconds()
+    ///    .map(|tc| tc.to_string())
+    ///    .collect();
+    ///assert_eq!(ticks, ["00:00:01:00", "00:00:02:00"]);
+    ///```
+    pub fn iter_seconds(&self) -> impl Iterator<Item = Timecode<FR>> {
+        let first = if self.start.snap_prev_second_boundary() == self.start {
+            self.start
+        } else {
+            self.start.snap_next_second_boundary()
+        };
+        let end = self.end.to_frame_count();
+
+        std::iter::successors(Some(first), |tc| Some(tc.snap_next_second_boundary()))
+            .take_while(move |tc| tc.to_frame_count() < end)
+    }
+
+    ///The `n - 1` cut points that split this range into `n` segments of as equal length as
+    ///possible. When the duration does not divide evenly, the earlier segments are one frame
+    ///longer.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, TimecodeRange};
+    ///let start: Timecode<NDF<25>> = "00:00:00:00".parse().unwrap();
+    ///let end: Timecode<NDF<25>> = "00:00:04:00".parse().unwrap();
+    ///
+    ///let cuts: Vec<String> = TimecodeRange::new(start, end)
+    ///    .split_into(4)
+    ///    .iter()
+    ///    .map(|tc| tc.to_string())
+    ///    .collect();
+    ///assert_eq!(cuts, ["00:00:01:00", "00:00:02:00", "00:00:03:00"]);
+    ///```
+    ///
+    ///PANIC: if `n` is zero
+    pub fn split_into(&self, n: usize) -> Vec<Timecode<FR>> {
+        assert!(n > 0, "must split into at least one segment");
+
+        let duration = self.duration().0 as usize;
+        let (len, remainder) = (duration / n, duration % n);
+        let start = self.start.to_frame_count();
+
+        (1..n)
+            .map(|i| {
+                let offset = i * len + i.min(remainder);
+                Timecode::from_frames(
+                    &Frames(start + offset as FrameCount),
+                    self.start.framerate(),
+                )
+            })
+            .collect()
+    }
+
+    ///Iterate from `start` towards `end`, `step` frames at a time. Every yielded timecode is
+    ///built from its frame count, so dropframe ranges only ever produce legal addresses.
+    ///
+    ///PANIC: if `step` is zero frames
+    pub fn iter_frames(&self, step: Frames) -> FrameStep<FR> {
+        assert!(step.0 > 0, "step must be at least one frame");
+
+        FrameStep {
+            next: self.start.to_frame_count(),
+            end: self.end.to_frame_count(),
+            step: step.0,
+            framerate: *self.start.framerate(),
+        }
+    }
+}
+
+impl<FR: Framerate> Timecode<FR> {
+    ///Iterate from this timecode towards `end` (exclusive), `step` frames at a time. Shorthand for
+    ///`TimecodeRange::new(self, end).iter_frames(step)`.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode, Frames};
+    ///let start: Timecode<DF<30>> = "00:00:58;00".parse().unwrap();
+    ///let end: Timecode<DF<30>> = "00:01:01;00".parse().unwrap();
+    ///
+    ///let thumbs: Vec<String> = start.iter_step(end, Frames(30)).map(|tc| tc.to_string()).collect();
+    ///assert_eq!(thumbs, ["00:00:58;00", "00:00:59;00", "00:01:00;02"]);
+    ///```
+    ///
+    ///PANIC: if `step` is zero frames, or if `end` is before this timecode
+    pub fn iter_step(self, end: Timecode<FR>, step: Frames) -> FrameStep<FR> {
+        TimecodeRange::new(self, end).iter_frames(step)
+    }
+}
+
+///Iterator returned by [`TimecodeRange::iter_frames`] and [`Timecode::iter_step`]
+#[derive(Clone, Debug)]
+pub struct FrameStep<FR> {
+    next: FrameCount,
+    end: FrameCount,
+    step: FrameCount,
+    framerate: FR,
+}
+
+impl<FR: Framerate> Iterator for FrameStep<FR> {
+    type Item = Timecode<FR>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+
+        let tc = Timecode::from_frames(&Frames(self.next), &self.framerate);
+        self.next = self.next.saturating_add(self.step);
+
+        Some(tc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framerates::*;
+
+    #[test]
+    fn iter_df_legal_and_monotonic() {
+        let start: Timecode<DF<30>> = "00:00:59;00".parse().unwrap();
+        let end: Timecode<DF<30>> = "00:01:01;00".parse().unwrap();
+        let range = TimecodeRange::new(start, end);
+
+        let mut last = None;
+        for tc in range.iter_frames(Frames(1)) {
+            let reparsed: Timecode<DF<30>> = tc.to_string().parse().unwrap();
+            assert_eq!(reparsed, tc);
+
+            let count = tc.to_frame_count();
+            if let Some(last) = last {
+                assert!(count > last);
+            }
+            last = Some(count);
+        }
+
+        assert_eq!(last, Some(end.to_frame_count() - 1));
+    }
+
+    #[test]
+    fn iter_step() {
+        let start: Timecode<DF<30>> = "00:00:59;00".parse().unwrap();
+        let end: Timecode<DF<30>> = "00:01:01;00".parse().unwrap();
+        let range = TimecodeRange::new(start, end);
+
+        let tcs: Vec<_> = range
+            .iter_frames(Frames(30))
+            .map(|tc| tc.to_string())
+            .collect();
+        assert_eq!(tcs, ["00:00:59;00", "00:01:00;02"]);
+    }
+
+    #[test]
+    fn ranges_in_hashset() {
+        use std::collections::HashSet;
+
+        let a: Timecode<DF<30>> = "00:00:59;00".parse().unwrap();
+        let b: Timecode<DF<30>> = "00:01:01;00".parse().unwrap();
+        let c: Timecode<DF<30>> = "00:01:02;00".parse().unwrap();
+
+        let mut set = HashSet::new();
+        assert!(set.insert(TimecodeRange::new(a, b)));
+        assert!(set.insert(TimecodeRange::new(a, c)));
+        assert!(!set.insert(TimecodeRange::new(a, b)));
+        assert!(!set.insert(TimecodeRange::new(
+            a,
+            b.with_separator(Some(crate::parser::Seperator::Colon))
+        )));
+
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&TimecodeRange::new(a, c)));
+    }
+
+    #[test]
+    fn iter_seconds_df() {
+        let start: Timecode<DF<30>> = "00:00:58;00".parse().unwrap();
+        let end: Timecode<DF<30>> = "00:01:02;00".parse().unwrap();
+        let range = TimecodeRange::new(start, end);
+
+        let ticks: Vec<_> = range.iter_seconds().collect();
+        for tc in &ticks {
+            let reparsed: Timecode<DF<30>> = tc.to_string().parse().unwrap();
+            assert_eq!(&reparsed, tc);
+        }
+
+        let ticks: Vec<_> = ticks.iter().map(|tc| tc.to_string()).collect();
+        assert_eq!(
+            ticks,
+            ["00:00:58;00", "00:00:59;00", "00:01:00;02", "00:01:01;00"]
+        );
+
+        let start: Timecode<DF<30>> = "00:00:59;01".parse().unwrap();
+        let ticks: Vec<_> = TimecodeRange::new(start, end)
+            .iter_seconds()
+            .map(|tc| tc.to_string())
+            .collect();
+        assert_eq!(ticks, ["00:01:00;02", "00:01:01;00"]);
+    }
+
+    #[test]
+    fn at_fraction() {
+        let start: Timecode<DF<30>> = "00:00:50;00".parse().unwrap();
+        let end: Timecode<DF<30>> = "00:01:10;00".parse().unwrap();
+        let range = TimecodeRange::new(start, end);
+
+        assert_eq!(range.at_fraction(0.0), start);
+        assert_eq!(range.at_fraction(0.5).to_string(), "00:00:59;29");
+        assert_eq!(range.at_fraction(1.0), end);
+
+        assert_eq!(range.at_fraction(-1.0), start);
+        assert_eq!(range.at_fraction(2.0), end);
+    }
+
+    #[test]
+    fn split_into() {
+        let start: Timecode<NDF<25>> = "01:00:00:00".parse().unwrap();
+        let range = TimecodeRange::new(start, start + Frames(100));
+
+        let cuts = range.split_into(3);
+        let offsets: Vec<_> = cuts
+            .iter()
+            .map(|tc| tc.to_frame_count() - start.to_frame_count())
+            .collect();
+        assert_eq!(offsets, [34, 67]);
+        assert_eq!(cuts[0].to_string(), "01:00:01:09");
+
+        assert!(range.split_into(1).is_empty());
+        assert_eq!(range.split_into(100).len(), 99);
+    }
+
+    #[test]
+    #[should_panic]
+    fn iter_zero_step() {
+        let start: Timecode<NDF<30>> = "00:00:00:00".parse().unwrap();
+        let _ = TimecodeRange::new(start, start).iter_frames(Frames(0));
+    }
+
+    #[test]
+    fn timecode_iter_step() {
+        let start: Timecode<DF<30>> = "00:09:59;00".parse().unwrap();
+        let end = start + Frames(300);
+
+        let tcs: Vec<_> = start.iter_step(end, Frames(30)).collect();
+        assert_eq!(tcs.len(), 10);
+        assert_eq!(tcs[1].to_string(), "00:10:00;00");
+        for w in tcs.windows(2) {
+            assert_eq!(w[1].to_frame_count() - w[0].to_frame_count(), 30);
+        }
+
+        assert_eq!(start.iter_step(start, Frames(1)).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn iter_step_zero() {
+        let start: Timecode<NDF<30>> = "00:00:00:00".parse().unwrap();
+        let _ = start.iter_step(start + Frames(30), Frames(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn backwards_range() {
+        let start: Timecode<NDF<30>> = "00:00:01:00".parse().unwrap();
+        let end: Timecode<NDF<30>> = "00:00:00:00".parse().unwrap();
+        let _ = TimecodeRange::new(start, end);
+    }
+}
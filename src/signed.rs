@@ -0,0 +1,123 @@
+use crate::{Framerate, Frames, Timecode, ToFrames};
+
+///A timecode which may be before `00:00:00:00`, such as an offset into pre-roll. Stored as a
+///sign and a magnitude, and displayed with a leading `-` when negative.
+///
+///```
+///# use timecode::{framerates::*, Timecode, signed::SignedTimecode};
+///let tc: Timecode<NDF<25>> = "00:00:02:00".parse().unwrap();
+///let t = SignedTimecode::new(true, tc);
+///
+///assert_eq!(t.to_string(), "-00:00:02:00");
+///assert_eq!(t.signed_frames(), -50);
+///```
+#[derive(Copy, Debug, Eq, PartialEq, Hash, Clone)]
+pub struct SignedTimecode<FR> {
+    negative: bool,
+    tc: Timecode<FR>,
+}
+
+impl<FR: Framerate> SignedTimecode<FR> {
+    ///A zero `tc` is never negative, whatever `negative` is.
+    pub fn new(negative: bool, tc: Timecode<FR>) -> Self {
+        Self {
+            negative: negative && tc.to_frame_count() > 0,
+            tc,
+        }
+    }
+
+    ///Build from a signed frame count.
+    ///
+    ///PANIC: if the magnitude does not fit in a [`FrameCount`](crate::FrameCount)
+    pub fn from_signed_frames(frames: i64, fr: &FR) -> Self {
+        let magnitude = frames.unsigned_abs().try_into().expect("Too large");
+        Self::new(frames < 0, Timecode::from_frames(&Frames(magnitude), fr))
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    ///The timecode without its sign
+    pub fn magnitude(&self) -> &Timecode<FR> {
+        &self.tc
+    }
+
+    ///Number of frames from `00:00:00:00`, negative if before it
+    pub fn signed_frames(&self) -> i64 {
+        let count = self.tc.to_frame_count() as i64;
+        if self.negative {
+            -count
+        } else {
+            count
+        }
+    }
+}
+
+impl<FR: Framerate> Timecode<FR> {
+    ///Offset of this timecode from the start of a reel. Timecodes in the pre-roll before
+    ///`reel_start` are negative.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, Timecode};
+    ///let reel_start: Timecode<NDF<25>> = "01:00:00:00".parse().unwrap();
+    ///let tc: Timecode<NDF<25>> = "00:58:00:00".parse().unwrap();
+    ///assert_eq!(tc.relative_to_reel(&reel_start).to_string(), "-00:02:00:00");
+    ///```
+    ///
+    ///PANIC: if the framerates differ
+    pub fn relative_to_reel(&self, reel_start: &Timecode<FR>) -> SignedTimecode<FR> {
+        assert!(
+            self.framerate() == reel_start.framerate(),
+            "reel start must have the same framerate"
+        );
+
+        let offset = self.to_frame_count() as i64 - reel_start.to_frame_count() as i64;
+        SignedTimecode::from_signed_frames(offset, self.framerate())
+    }
+}
+
+impl<FR: Framerate> std::fmt::Display for SignedTimecode<FR> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.tc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framerates::*;
+
+    #[test]
+    fn relative_to_reel() {
+        let reel_start: Timecode<DF<30>> = "00:58:00;02".parse().unwrap();
+
+        let before: Timecode<DF<30>> = "00:57:59;28".parse().unwrap();
+        let t = before.relative_to_reel(&reel_start);
+        assert!(t.is_negative());
+        assert_eq!(t.signed_frames(), -2);
+        assert_eq!(t.to_string(), "-00:00:00;02");
+
+        let after: Timecode<DF<30>> = "01:00:00;00".parse().unwrap();
+        let t = after.relative_to_reel(&reel_start);
+        assert!(!t.is_negative());
+        assert_eq!(t.signed_frames(), 3596);
+        assert_eq!(t.to_string(), "00:01:59;28");
+
+        let t = reel_start.relative_to_reel(&reel_start);
+        assert!(!t.is_negative());
+        assert_eq!(t.to_string(), "00:00:00;00");
+    }
+
+    #[test]
+    fn negative_zero() {
+        let zero: Timecode<NDF<25>> = "00:00:00:00".parse().unwrap();
+        assert_eq!(
+            SignedTimecode::new(true, zero),
+            SignedTimecode::new(false, zero)
+        );
+    }
+}
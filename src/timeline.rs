@@ -0,0 +1,231 @@
+//!Helpers that operate on whole lists of timecodes, such as the events of an EDL.
+
+use crate::{DynFramerate, Framerate, Frames, Overflow, Timecode, ToFrames};
+use std::time::Duration;
+
+///Move every timecode in `tcs` forward by `offset` frames.
+///
+///If any timecode would overflow or pass [`Timecode::max_frame_count`], nothing is changed and
+///[`Overflow`] is returned.
+///
+///```
+///# use timecode::{framerates::*, Timecode, Frames, timeline::shift_all};
+///let mut tcs: Vec<Timecode<NDF<25>>> = vec![
+///    "00:00:00:00".parse().unwrap(),
+///    "00:00:10:00".parse().unwrap(),
+///];
+///shift_all(&mut tcs, Frames(25)).unwrap();
+///assert_eq!(tcs[0].to_string(), "00:00:01:00");
+///assert_eq!(tcs[1].to_string(), "00:00:11:00");
+///```
+pub fn shift_all<FR: Framerate>(tcs: &mut [Timecode<FR>], offset: Frames) -> Result<(), Overflow> {
+    //add_frames checks the same limit, so once the latest one fits they all do
+    if let Some(latest) = tcs.iter().max_by_key(|tc| tc.to_frame_count()) {
+        latest.add_frames(offset)?;
+    }
+
+    for tc in tcs.iter_mut() {
+        *tc = tc.add_frames(offset)?;
+    }
+
+    Ok(())
+}
+
+///A problem found by [`check_monotonic`]
+#[derive(Copy, Debug, Eq, PartialEq, Clone)]
+pub enum MonotonicError {
+    ///This timecode is at or before the one preceding it
+    NotIncreasing,
+    ///This timecode is more than the allowed gap after the one preceding it. Holds the gap.
+    Gap(Frames),
+    ///This timecode has a different framerate than the one preceding it, so their frame counts
+    ///can't be compared
+    FramerateMismatch,
+}
+
+///Check that every timecode in `tcs` is strictly after the one before it, and no more than
+///`max_gap` frames after it. Each problem is reported with the index of the later timecode.
+///
+///Neighbouring timecodes must share a framerate, since `max_gap` is counted in frames. This only
+///matters for [`DynFramerate`] timecodes.
+///
+///```
+///# use timecode::{framerates::*, Timecode, Frames, timeline::{check_monotonic, MonotonicError}};
+///let tcs: Vec<Timecode<NDF<25>>> = ["00:00:00:00", "00:00:01:00", "00:00:00:10"]
+///    .iter()
+///    .map(|s| s.parse().unwrap())
+///    .collect();
+///
+///let errors = check_monotonic(&tcs, Frames(25)).unwrap_err();
+///assert_eq!(errors, [(2, MonotonicError::NotIncreasing)]);
+///```
+pub fn check_monotonic<FR: Framerate>(
+    tcs: &[Timecode<FR>],
+    max_gap: Frames,
+) -> Result<(), Vec<(usize, MonotonicError)>> {
+    let errors: Vec<_> = tcs
+        .windows(2)
+        .enumerate()
+        .filter_map(|(i, pair)| {
+            if pair[0].framerate() != pair[1].framerate() {
+                return Some((i + 1, MonotonicError::FramerateMismatch));
+            }
+
+            let prev = pair[0].to_frame_count();
+            let next = pair[1].to_frame_count();
+
+            if next <= prev {
+                Some((i + 1, MonotonicError::NotIncreasing))
+            } else if next - prev > max_gap.0 {
+                Some((i + 1, MonotonicError::Gap(Frames(next - prev))))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+///Find every pair of timecodes whose real time positions are within `tol` of each other, even
+///if they have different framerates. Each pair is returned once, as `(earlier index, later
+///index)`.
+///
+///```
+///# use timecode::{Timecode, timeline::find_realtime_collisions};
+///# use std::time::Duration;
+///let tcs = [
+///    Timecode::new_with_fr("00:00:01:00", "25").unwrap(),
+///    Timecode::new_with_fr("00:00:01:00", "30").unwrap(),
+///    Timecode::new_with_fr("00:00:02:00", "30").unwrap(),
+///];
+///assert_eq!(find_realtime_collisions(&tcs, Duration::ZERO), [(0, 1)]);
+///```
+pub fn find_realtime_collisions(
+    tcs: &[Timecode<DynFramerate>],
+    tol: Duration,
+) -> Vec<(usize, usize)> {
+    let durations: Vec<_> = tcs.iter().map(|tc| tc.to_duration()).collect();
+
+    let mut collisions = vec![];
+    for (i, a) in durations.iter().enumerate() {
+        for (j, b) in durations.iter().enumerate().skip(i + 1) {
+            if a.abs_diff(*b) <= tol {
+                collisions.push((i, j));
+            }
+        }
+    }
+
+    collisions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{framerates::*, FrameCount};
+
+    #[test]
+    fn shift_slice() {
+        let original: Vec<Timecode<DF<30>>> = ["00:00:00;00", "00:00:59;29", "01:00:00;00"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+
+        let mut tcs = original.clone();
+        shift_all(&mut tcs, Frames(100)).unwrap();
+
+        for (before, after) in original.iter().zip(tcs.iter()) {
+            assert_eq!(after.to_frame_count() - before.to_frame_count(), 100);
+        }
+        assert_eq!(tcs[1].to_string(), "00:01:03;11");
+    }
+
+    #[test]
+    fn shift_overflow_unchanged() {
+        let original: Vec<Timecode<NDF<30>>> = ["00:00:00:00", "01:00:00:00"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+
+        let mut tcs = original.clone();
+        let offset = Frames(FrameCount::MAX - 10);
+        assert!(shift_all(&mut tcs, offset).is_err());
+        assert_eq!(tcs, original);
+
+        //fits in a FrameCount, but takes 01:00:00:00 past 255 hours
+        let offset = Frames(255 * 60 * 60 * 30);
+        assert!(shift_all(&mut tcs, offset).is_err());
+        assert_eq!(tcs, original);
+
+        let offset = Frames(254 * 60 * 60 * 30);
+        assert!(shift_all(&mut tcs, offset).is_ok());
+        assert_eq!(tcs[1].h, 255);
+    }
+
+    #[test]
+    fn monotonic_regression_and_gap() {
+        let tcs: Vec<Timecode<DF<30>>> = [
+            "00:00:59;28",
+            "00:01:00;02",
+            "00:01:00;02",
+            "00:01:00;10",
+            "00:02:00;10",
+            "00:02:00;11",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+
+        assert_eq!(
+            check_monotonic(&tcs, Frames(30)),
+            Err(vec![
+                (2, MonotonicError::NotIncreasing),
+                (4, MonotonicError::Gap(Frames(1798))),
+            ])
+        );
+
+        assert_eq!(check_monotonic(&tcs[..2], Frames(2)), Ok(()));
+        assert_eq!(check_monotonic::<DF<30>>(&[], Frames(0)), Ok(()));
+    }
+
+    #[test]
+    fn monotonic_mixed_framerates() {
+        //25 frames at 25fps is one second, and 29 frames at 29.97fps is before that
+        let tcs = [
+            Timecode::new_with_fr("00:00:01:00", "25").unwrap(),
+            Timecode::new_with_fr("00:00:00;29", "29.97").unwrap(),
+            Timecode::new_with_fr("00:00:01;00", "29.97").unwrap(),
+        ];
+
+        assert_eq!(
+            check_monotonic(&tcs, Frames(30)),
+            Err(vec![(1, MonotonicError::FramerateMismatch)])
+        );
+        assert_eq!(check_monotonic(&tcs[1..], Frames(30)), Ok(()));
+    }
+
+    #[test]
+    fn realtime_collisions() {
+        let tcs = [
+            Timecode::new_with_fr("01:00:00;00", "29.97").unwrap(),
+            Timecode::new_with_fr("00:59:59:24", "25").unwrap(),
+            Timecode::new_with_fr("01:00:00:00", "25").unwrap(),
+            Timecode::new_with_fr("02:00:00:00", "24").unwrap(),
+        ];
+
+        //01:00:00;00 at 29.97 is 3.6ms before 01:00:00:00, and 36.4ms after 00:59:59:24
+        assert_eq!(
+            find_realtime_collisions(&tcs, Duration::from_millis(5)),
+            [(0, 2)]
+        );
+        assert_eq!(
+            find_realtime_collisions(&tcs, Duration::from_millis(40)),
+            [(0, 1), (0, 2), (1, 2)]
+        );
+        assert!(find_realtime_collisions(&tcs, Duration::from_millis(1)).is_empty());
+    }
+}
@@ -24,6 +24,8 @@ impl WarningContainer for () {
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum TimecodeValidationError {
+    ///The hours field is 24 or more. Only returned by [`UnvalidatedTC::validate_strict`].
+    InvalidHours(u8),
     ///The minutes field is invalid
     InvalidMin(u8),
     ///The seconds field is invalid
@@ -41,6 +43,7 @@ pub enum TimecodeValidationError {
 impl std::fmt::Display for TimecodeValidationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            TimecodeValidationError::InvalidHours(n) => write!(f, "Invalid hours {}", n),
             TimecodeValidationError::InvalidMin(n) => write!(f, "Invalid minutes {}", n),
             TimecodeValidationError::InvalidSec(n) => write!(f, "Invalid seconds {}", n),
             TimecodeValidationError::InvalidFrames(n) => write!(f, "Invalid frames {}", n),
@@ -63,6 +66,14 @@ pub enum TimecodeValidationWarning {
     MismatchSep,
 }
 
+impl std::fmt::Display for TimecodeValidationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimecodeValidationWarning::MismatchSep => write!(f, "separator mismatch"),
+        }
+    }
+}
+
 ///Used internally when calling [`UnvalidatedTC::validate`]. If `Ok(())` is returned, the
 ///unvalidated timecode will be directly copied into a new [`Timecode`]
 pub trait ValidateableFramerate: Framerate + Copy {
@@ -101,19 +112,41 @@ impl UnvalidatedTC {
         &self,
         fr: &FR,
     ) -> Result<Timecode<FR>, TimecodeValidationError> {
-        fr.validate(&self, &mut ()).map(|_| {
+        fr.validate(self, &mut ()).map(|_| {
             let UnvalidatedTC { h, m, s, f, .. } = *self;
 
-            Timecode {
-                h,
-                m,
-                s,
-                f,
-                framerate: *fr,
-            }
+            Timecode::from_parts(h, m, s, f, *fr)
         })
     }
 
+    ///Like [`UnvalidatedTC::validate`], but also rejects timecodes at or beyond `24:00:00:00`.
+    ///The other validate methods allow any hour, for timelines longer than a day.
+    ///
+    ///```
+    ///# use timecode::{framerates::*, validate::TimecodeValidationError};
+    ///let raw_tc = timecode::unvalidated("30:00:00:00").unwrap();
+    ///
+    ///assert!(raw_tc.validate::<NDF<25>>().is_ok());
+    ///assert_eq!(
+    ///    raw_tc.validate_strict::<NDF<25>>(),
+    ///    Err(TimecodeValidationError::InvalidHours(30))
+    ///);
+    ///```
+    pub fn validate_strict<FR: ValidateableFramerate + ConstFramerate>(
+        &self,
+    ) -> Result<Timecode<FR>, TimecodeValidationError> {
+        self.validate_strict_with_fr(&FR::new())
+    }
+
+    ///Same as validate_strict, but with a dynamic framerate parameter
+    pub fn validate_strict_with_fr<FR: ValidateableFramerate>(
+        &self,
+        fr: &FR,
+    ) -> Result<Timecode<FR>, TimecodeValidationError> {
+        helper_v_hours(self.h)?;
+        self.validate_with_fr(fr)
+    }
+
     ///This validates the timecode while returning warnings about potentially incorrect timecodes.
     ///
     ///In this example, `01:02:00:25` is valid for both formats, but the seperator should be `;`
@@ -158,19 +191,10 @@ impl UnvalidatedTC {
         fr: &FR,
     ) -> Result<(Timecode<FR>, Vec<TimecodeValidationWarning>), TimecodeValidationError> {
         let mut warnings = vec![];
-        fr.validate(&self, &mut warnings).map(|_| {
+        fr.validate(self, &mut warnings).map(|_| {
             let UnvalidatedTC { h, m, s, f, .. } = *self;
 
-            (
-                Timecode {
-                    h,
-                    m,
-                    s,
-                    f,
-                    framerate: *fr,
-                },
-                warnings,
-            )
+            (Timecode::from_parts(h, m, s, f, *fr), warnings)
         })
     }
 
@@ -184,11 +208,7 @@ impl UnvalidatedTC {
     ///```
     ///# use timecode::framerates::NDF;
     ///# use timecode::parser;
-    ///# use std::convert::TryInto;
-    ///let raw_tc = parser::UnvalidatedTC {
-    ///    h: 1, m: 2, s: 0, f: 25,
-    ///    seperator: ';'.try_into().unwrap()
-    ///};
+    ///let raw_tc = parser::UnvalidatedTC::new(1, 2, 0, 25, ';').unwrap();
     ///
     ///let tc = unsafe { raw_tc.validate_unchecked::<NDF<30>>() };
     ///
@@ -199,19 +219,25 @@ impl UnvalidatedTC {
     }
 
     ///see validate_unchecked
+    ///
+    ///# Safety
+    ///
+    ///Same as [`UnvalidatedTC::validate_unchecked`].
     pub unsafe fn validate_unchecked_with_fr<FR: Framerate>(&self, fr: &FR) -> Timecode<FR> {
         let UnvalidatedTC { h, m, s, f, .. } = *self;
 
-        Timecode {
-            h,
-            m,
-            s,
-            f,
-            framerate: *fr,
-        }
+        Timecode::from_parts(h, m, s, f, *fr)
     }
 }
 
+fn helper_v_hours(h: u8) -> Result<(), TimecodeValidationError> {
+    if h >= 24 {
+        return Err(TimecodeValidationError::InvalidHours(h));
+    }
+
+    Ok(())
+}
+
 fn helper_v_ms(m: u8, s: u8) -> Result<(), TimecodeValidationError> {
     if m >= 60 {
         return Err(TimecodeValidationError::InvalidMin(m));
@@ -228,6 +254,12 @@ fn helper_v_sep<FR: Framerate>(
     seperator: Seperator,
     fr: &FR,
 ) -> Result<(), TimecodeValidationWarning> {
+    //a period is written in place of a colon, so it is only wrong for dropframe
+    let seperator = match seperator {
+        Seperator::Period => Seperator::Colon,
+        sep => sep,
+    };
+
     if fr.to_sep() != seperator.into() {
         return Err(TimecodeValidationWarning::MismatchSep);
     }
@@ -248,13 +280,12 @@ fn helper_v_max_frame<FR: Framerate>(
 
 ///drop frame rules are the same regardless of framerate.
 fn helper_v_drop_frame(
-    _drop_frames: FrameCount,
+    drop_frames: FrameCount,
     m: u8,
     s: u8,
     f: FrameCount,
 ) -> Result<(), TimecodeValidationError> {
-    //TODO should this be drop_frames?
-    if m % 10 != 0 && s == 0 && f < 2 {
+    if !m.is_multiple_of(10) && s == 0 && f < drop_frames {
         return Err(TimecodeValidationError::InvalidFrames(f));
     }
 
@@ -272,9 +303,9 @@ impl<F: Framerate + Copy> ValidateableFramerate for F {
         } = *input_tc;
 
         helper_v_ms(m, s)?;
-        helper_v_sep(seperator, self)
-            .err()
-            .map(|e| warnings.add_warning(e));
+        if let Err(e) = helper_v_sep(seperator, self) {
+            warnings.add_warning(e);
+        }
 
         if let Some(drop_frames) = self.drop_frames() {
             helper_v_drop_frame(drop_frames, m, s, f)?;
@@ -285,3 +316,98 @@ impl<F: Framerate + Copy> ValidateableFramerate for F {
         Ok(())
     }
 }
+
+///Parse and validate every input, pairing each result with its index in `inputs`. Useful for
+///reporting which lines of a file failed and why.
+///
+///```
+///# use timecode::{framerates::*, validate::validate_all};
+///let results = validate_all::<NDF<25>>(&["00:00:00:00", "00:00:00:25"]);
+///assert!(results[0].1.is_ok());
+///assert!(results[1].1.is_err());
+///```
+pub fn validate_all<FR: ValidateableFramerate + ConstFramerate>(
+    inputs: &[&str],
+) -> Vec<(usize, Result<Timecode<FR>, TimecodeValidationError>)> {
+    inputs.iter().map(|s| s.parse()).enumerate().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framerates::*;
+
+    #[test]
+    fn validate_all_indices() {
+        let inputs = [
+            "00:00:00;00",
+            "not a timecode",
+            "00:01:00;00",
+            "00:01:00;02",
+            "00:61:00;02",
+        ];
+
+        let results = validate_all::<DF<30>>(&inputs);
+
+        assert_eq!(results.len(), inputs.len());
+        for (i, (idx, _)) in results.iter().enumerate() {
+            assert_eq!(i, *idx);
+        }
+
+        assert!(results[0].1.is_ok());
+        assert_eq!(results[1].1, Err(TimecodeValidationError::Unparsed));
+        assert_eq!(results[2].1, Err(TimecodeValidationError::InvalidFrames(0)));
+        assert_eq!(results[3].1.unwrap().to_string(), "00:01:00;02");
+        assert_eq!(results[4].1, Err(TimecodeValidationError::InvalidMin(61)));
+    }
+
+    #[test]
+    fn high_dropframe() {
+        assert!("00:01:00;03".parse::<Timecode<DF<60>>>().is_err());
+        assert!("00:01:00;04".parse::<Timecode<DF<60>>>().is_ok());
+        assert!("00:01:00;07".parse::<Timecode<DF<120>>>().is_err());
+        assert!("00:01:00;08".parse::<Timecode<DF<120>>>().is_ok());
+        assert!("00:10:00;00".parse::<Timecode<DF<120>>>().is_ok());
+    }
+
+    #[test]
+    fn strict_hours() {
+        let raw_tc = crate::unvalidated("23:59:59;29").unwrap();
+        assert!(raw_tc.validate_strict::<DF<30>>().is_ok());
+
+        let raw_tc = crate::unvalidated("24:00:00;00").unwrap();
+        assert!(raw_tc.validate::<DF<30>>().is_ok());
+        assert_eq!(
+            raw_tc.validate_strict::<DF<30>>(),
+            Err(TimecodeValidationError::InvalidHours(24))
+        );
+
+        //other fields are still checked
+        let raw_tc = crate::unvalidated("01:01:00;00").unwrap();
+        assert_eq!(
+            raw_tc.validate_strict::<DF<30>>(),
+            Err(TimecodeValidationError::InvalidFrames(0))
+        );
+    }
+
+    #[test]
+    fn period_separator() {
+        let raw_tc = crate::unvalidated("01:02:03.04").unwrap();
+
+        let (tc, warnings) = raw_tc.validate_with_warnings::<NDF<25>>().unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(tc.to_string(), "01:02:03:04");
+
+        let (tc, warnings) = raw_tc.validate_with_warnings::<DF<30>>().unwrap();
+        assert_eq!(warnings, [TimecodeValidationWarning::MismatchSep]);
+        assert_eq!(tc.to_string(), "01:02:03;04");
+    }
+
+    #[test]
+    fn warning_display() {
+        assert_eq!(
+            TimecodeValidationWarning::MismatchSep.to_string(),
+            "separator mismatch"
+        );
+    }
+}
@@ -24,3 +24,43 @@ try{
 }
 
 console.log((new Timecode("01:00:00:04", "60")).frame_count())
+
+let underflow = new Timecode("00:00:01:00", "25");
+console.assert(underflow.sub_frames(25).tc() == "00:00:00:00");
+try {
+    underflow.sub_frames(26);
+    console.assert(false, "sub_frames should not go below 00:00:00:00");
+} catch (e) {
+    console.log("sub_frames below zero throws:", e);
+}
+
+console.assert(underflow.sub_frames_saturating(25).tc() == "00:00:00:00");
+console.assert(underflow.sub_frames_saturating(26).tc() == "00:00:00:00");
+
+let checked = Timecode.new_checked("00:01:00:02", "29.97");
+console.assert(checked.timecode.tc() == "00:01:00;02");
+console.assert(checked.warnings.length == 1 && checked.warnings[0] == "separator mismatch");
+console.assert(Timecode.new_checked("00:01:00;02", "29.97").warnings.length == 0);
+
+let ntsc = new Timecode("01:00:00;00", "29.97");
+let pal = ntsc.convert_to("25");
+console.assert(ntsc.closeTo(pal, 1));
+console.assert(!ntsc.closeTo(pal.add_frames(2), 1));
+console.assert(ntsc.closeTo(pal.add_frames(2), 3));
+
+let cues = [new Timecode("00:00:02:00", "25"), new Timecode("00:00:01:00", "25")];
+cues.sort((a, b) => a.cmp(b));
+console.assert(cues[0].tc() == "00:00:01:00");
+console.assert(cues[0].lt(cues[1]) && cues[1].gt(cues[0]));
+console.assert(cues[0].eq(new Timecode("00:00:01:00", "25")));
+console.assert(!cues[0].eq(new Timecode("00:00:01:00", "30")));
+try {
+    cues[0].cmp(new Timecode("00:00:01:00", "30"));
+    console.assert(false, "comparing different framerates should throw");
+} catch (e) {
+    console.log("cmp across framerates throws:", e);
+}
+
+console.assert(new Timecode("00:00:01:05", "25").total_seconds() == 1.2);
+console.assert(new Timecode("00:00:01:05", "25").total_ms() == 1200);
+console.assert(Math.abs(new Timecode("01:00:00;00", "29.97").total_seconds() - 3599.9964) < 1e-9);
@@ -41,3 +41,65 @@ exact_frame_diff = num_frames * (29.97 / 25.0)
 error = exact_frame_diff - num_frames_converted
 print(f"The exact number of converted frames is f{exact_frame_diff}.")
 print(f"Frame error is {error:.4f}")
+
+tc = Timecode("00:00:01:00", "25")
+assert str(tc.sub_frames(25)) == "00:00:00:00"
+try:
+    tc.sub_frames(26)
+    assert False, "sub_frames should not go below 00:00:00:00"
+except ValueError:
+    pass
+
+assert str(tc.sub_frames_saturating(25)) == "00:00:00:00"
+assert str(tc.sub_frames_saturating(26)) == "00:00:00:00"
+
+(tc, warnings) = Timecode.new_checked("00:01:00:02", "29.97")
+assert str(tc) == "00:01:00;02"
+assert warnings == ["separator mismatch"]
+(tc, warnings) = Timecode.new_checked("00:01:00;02", "29.97")
+assert warnings == []
+
+ntsc = Timecode("01:00:00;00", "29.97")
+pal = ntsc.convert_to("25")
+assert ntsc.close_to(pal)
+assert not ntsc.close_to(pal.add_frames(2))
+assert ntsc.close_to(pal.add_frames(2), frames=3)
+
+a = Timecode("00:00:01:00", "25")
+b = Timecode("00:00:02:00", "25")
+assert a < b and a <= b and b > a and b >= a
+assert a == Timecode("25", "25")
+assert a != b
+assert sorted([b, a]) == [a, b]
+assert len({a, b, Timecode("00:00:01:00", "25")}) == 2
+assert a != Timecode("00:00:01:00", "30")
+try:
+    a < Timecode("00:00:01:00", "30")
+    assert False, "comparing different framerates should raise"
+except ValueError:
+    pass
+
+a = Timecode("00:00:01:00", "25")
+b = Timecode("00:00:02:00", "25")
+assert str(a + b) == "00:00:03:00"
+assert str(a + 5) == "00:00:01:05"
+assert str(b - 5) == "00:00:01:20"
+assert b - a == 25
+assert a - b == -25
+try:
+    a - 26
+    assert False, "subtracting below 00:00:00:00 should raise"
+except ValueError:
+    pass
+try:
+    a + Timecode("00:00:01:00", "30")
+    assert False, "adding different framerates should raise"
+except ValueError:
+    pass
+
+import pickle
+
+for tc in [Timecode("01:00:00;00", "29.97"), Timecode("10:00:00:24", "25"), Timecode("00:00:01:00", "59.94")]:
+    copy = pickle.loads(pickle.dumps(tc))
+    assert copy == tc
+    assert copy.is_dropframe() == tc.is_dropframe()
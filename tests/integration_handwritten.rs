@@ -44,6 +44,64 @@ fn test_add_df() {
     add_single_frame::<DF<30>>("00:09:59;29", "00:10:00;00");
 }
 
+#[test]
+fn test_add_df_high() {
+    add_single_frame::<DF<60>>("00:00:59;59", "00:01:00;04");
+    add_single_frame::<DF<90>>("00:00:59;89", "00:01:00;06");
+    add_single_frame::<DF<90>>("00:09:59;89", "00:10:00;00");
+    add_single_frame::<DF<120>>("00:00:59;119", "00:01:00;08");
+    add_single_frame::<DF<120>>("00:09:59;119", "00:10:00;00");
+}
+
+#[test]
+fn test_to_frames_for_df_high() {
+    test_framecount::<DF<90>>("00:01:00;06", 5400);
+    test_framecount::<DF<90>>("00:10:00;00", 54000 - 9 * 6);
+    test_framecount::<DF<120>>("00:01:00;08", 7200);
+    test_framecount::<DF<120>>("00:10:00;00", 72000 - 9 * 8);
+    test_framecount::<DF<120>>("01:00:00;00", 6 * (72000 - 9 * 8));
+}
+
+///Walk every frame in the first `minutes` minutes, checking that each frame count maps to a legal
+///timecode one frame after the last, and back to the same count.
+fn df_brute_force<FR: ValidateableFramerate + ConstFramerate + std::fmt::Debug>(minutes: u32) {
+    let fr = FR::new();
+    let drop_frames = fr.drop_frames().unwrap();
+    let per_minute = fr.max_frame() * 60;
+    let total = minutes * per_minute - (minutes - minutes.div_ceil(10)) * drop_frames;
+
+    let mut last = Timecode::from_frames(&Frames(0), &fr);
+    for i in 1..total {
+        let tc = Timecode::from_frames(&Frames(i), &fr);
+        assert_eq!(tc.to_frame_count(), i);
+
+        if tc.f() == 0 || tc.f() == drop_frames && tc.s() == 0 && tc.m() % 10 != 0 {
+            assert_eq!(last.f(), fr.max_frame() - 1, "{} after {}", tc, last);
+        } else {
+            assert_eq!(tc.f(), last.f() + 1, "{} after {}", tc, last);
+        }
+
+        let reparsed: Timecode<FR> = tc.to_string().parse().unwrap();
+        assert_eq!(reparsed, tc);
+
+        last = tc;
+    }
+
+    assert_eq!(
+        Timecode::from_frames(&Frames(total), &fr).m() as u32,
+        minutes
+    );
+}
+
+#[test]
+fn test_df_brute_force() {
+    df_brute_force::<DF<30>>(20);
+    df_brute_force::<DF<60>>(20);
+    df_brute_force::<DF<90>>(20);
+    df_brute_force::<DF<120>>(20);
+    df_brute_force::<DF<150>>(20);
+}
+
 #[test]
 fn test_to_frames_for_ndf() {
     test_framecount::<NDF<30>>("00:01:02:00", 1860);
@@ -101,8 +159,7 @@ fn test_convert_symmetry_5994() {
     let bads = [3597, 5395, 7193, 17981, 19781];
     let near_bad = bads
         .iter()
-        .map(|x| ((-10)..10).map(move |n| ((*x as i32) + n) as u32))
-        .flatten();
+        .flat_map(|x| ((-10)..10).map(move |n| (*x + n) as u32));
 
     for i in near_bad {
         let input = Timecode::from_frames(&Frames(i), &DF::<30>);
@@ -112,3 +169,64 @@ fn test_convert_symmetry_5994() {
         assert_eq!(input, incheck);
     }
 }
+
+#[test]
+fn test_convert_symmetry_boundaries() {
+    let near_bad = DynFramerate::new_df(30)
+        .known_boundary_frames()
+        .into_iter()
+        .flat_map(|x| (x - 10)..(x + 10));
+
+    for i in near_bad {
+        let input = Timecode::from_frames(&Frames(i), &DF::<30>);
+        let output: Timecode<DF<60>> = input.convert();
+        let incheck = output.convert();
+        assert_eq!(input, incheck, "{}", i);
+    }
+}
+
+#[test]
+fn test_df_unchecked_all_addresses() {
+    //every address in the first day and the last possible hour, including ones validation would
+    //reject. None of them may panic, and legal ones must round trip.
+    let hours = (0..24).chain([255]);
+    let mut last = 0;
+    for h in hours {
+        for m in 0..60 {
+            for s in 0..60 {
+                for f in 0..30 {
+                    let raw = timecode::parser::UnvalidatedTC::new(h, m, s, f, ';').unwrap();
+                    let tc = unsafe { raw.validate_unchecked::<DF<30>>() };
+                    let count = tc.to_frame_count();
+
+                    if raw.validate::<DF<30>>().is_ok() {
+                        assert_eq!(Timecode::from_frames(&Frames(count), &DF::<30>), tc);
+                    }
+
+                    //dropped addresses repeat the frame before them, so counts never go back
+                    assert!(count >= last, "{}", tc);
+
+                    //must not underflow, even for dropped addresses
+                    let into_minute = tc.frames_into_minute();
+                    let into_hour = tc.frames_into_hour();
+                    assert!(into_minute <= into_hour, "{}", tc);
+                    assert!(into_hour < 60 * 60 * 30, "{}", tc);
+                    if s == 0 && f < 2 && m % 10 != 0 {
+                        assert_eq!(into_minute, 0, "{}", tc);
+                    }
+                    last = count;
+                }
+            }
+        }
+    }
+
+    let raw = timecode::parser::UnvalidatedTC::new(0, 1, 0, 0, ';').unwrap();
+    let tc = unsafe { raw.validate_unchecked::<DF<30>>() };
+    assert_eq!(tc.to_frame_count(), 1799);
+    assert_eq!(tc.frames_into_minute(), 0);
+    assert_eq!(tc.frames_into_hour(), 1799);
+
+    let raw = timecode::parser::UnvalidatedTC::new(255, 255, 255, u32::MAX, ';').unwrap();
+    let tc = unsafe { raw.validate_unchecked::<DF<120>>() };
+    assert_eq!(tc.to_frame_count(), u32::MAX);
+}
@@ -6,7 +6,7 @@ use std::{
 };
 
 use timecode::{
-    framerates::*, ConstFramerate, Convert, DynFramerate, Frames, Timecode, ToFrames,
+    framerates::*, ConstFramerate, Convert, DynFramerate, Frames, RoundMode, Timecode, ToFrames,
     ValidateableFramerate,
 };
 
@@ -57,6 +57,27 @@ where
     }
 }
 
+fn test_reference_frame_convert_exact<FRS, FRD, P>(path: P, fr_src: &FRS, fr_dst: &FRD)
+where
+    FRS: ValidateableFramerate + Debug + Eq,
+    FRD: ValidateableFramerate + Debug + Eq,
+    P: AsRef<Path>,
+{
+    let f = BufReader::new(File::open(path).unwrap());
+    for line in f.lines().map(|x| x.unwrap()) {
+        let parts: Vec<_> = line.split("|").collect();
+
+        let my_version = Timecode::from_frames(&Frames(parts[0].parse().unwrap()), fr_src);
+        let my_version = my_version.convert_with_fr_rounded(fr_dst, RoundMode::Nearest);
+        let reference_version = timecode::unvalidated(parts[1])
+            .unwrap()
+            .validate_with_fr(fr_dst)
+            .unwrap();
+
+        assert_eq!(my_version, reference_version, "{}", parts[0]);
+    }
+}
+
 #[test]
 fn test_reference_frame_count_2997() {
     test_reference_frame_count("./tests/samples/reference.txt", &DF2997::new());
@@ -119,3 +140,22 @@ fn test_reference_frame_count_convert_50_25_dyn() {
     let b: DynFramerate = "25".parse().unwrap();
     test_reference_frame_convert("./tests/samples/reference_convert_50.txt", &a, &b);
 }
+
+#[test]
+fn test_reference_frame_count_convert_exact() {
+    test_reference_frame_convert_exact(
+        "./tests/samples/reference_convert.txt",
+        &NDF::<25>,
+        &DF::<30>,
+    );
+    test_reference_frame_convert_exact(
+        "./tests/samples/reference_convert_rev.txt",
+        &DF::<30>,
+        &NDF::<25>,
+    );
+    test_reference_frame_convert_exact(
+        "./tests/samples/reference_convert_50.txt",
+        &NDF::<50>,
+        &NDF::<25>,
+    );
+}
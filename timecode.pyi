@@ -1,11 +1,30 @@
+from typing import overload
+
 class Timecode:
     def __init__(self, timecode_or_number_of_frames: str, framerate: str) -> None: ...
+    @staticmethod
+    def new_checked(timecode: str, framerate: str) -> tuple[Timecode, list[str]]: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: Timecode) -> bool: ...
+    def __le__(self, other: Timecode) -> bool: ...
+    def __gt__(self, other: Timecode) -> bool: ...
+    def __ge__(self, other: Timecode) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __add__(self, other: Timecode | int) -> Timecode: ...
+    @overload
+    def __sub__(self, other: Timecode) -> int: ...
+    @overload
+    def __sub__(self, other: int) -> Timecode: ...
+    def __reduce__(self) -> tuple[type[Timecode], tuple[str, str]]: ...
     def ts(self) -> str: ...
     def add(self, tc: Timecode) -> Timecode: ...
     def add_frames(self, frames: int) -> Timecode: ...
     def sub_frames(self, frames: int) -> Timecode: ...
+    def sub_frames_saturating(self, frames: int) -> Timecode: ...
+    def close_to(self, other: Timecode, frames: int = 1) -> bool: ...
     def frame_count(self) -> int: ...
     def convert_to(self, framerate: str) -> Timecode: ...
     def convert_with_start(self, framerate: str, start: Timecode) -> Timecode: ...